@@ -0,0 +1,74 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use multiversx_sc_snippets::{
+    imports::{Bech32Address, Interactor},
+    sdk::gateway::{GatewayAsyncService, GetAccountRequest},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::RegistryError;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// One deployed (or upgraded) contract instance.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeploymentRecord {
+    pub address: Bech32Address,
+    /// Hex-encoded hash of the code currently on chain.
+    pub code_hash: String,
+    pub deployer: Bech32Address,
+    /// Hex-encoded hash of the deploy or upgrade transaction.
+    pub tx_hash: String,
+    /// Unix timestamp, in seconds, of the moment the record was written.
+    pub timestamp: u64,
+    /// Contract crate version, as found in the ABI `buildInfo`.
+    pub abi_version: String,
+}
+
+impl DeploymentRecord {
+    /// Builds a record for a deploy or upgrade that just went through,
+    /// reading the code hash of `address` from the gateway.
+    pub async fn fetch(
+        interactor: &Interactor,
+        address: Bech32Address,
+        deployer: Bech32Address,
+        tx_hash: &[u8],
+        abi_version: &str,
+    ) -> Result<Self, RegistryError> {
+        Self::fetch_from(&interactor.proxy, address, deployer, tx_hash, abi_version).await
+    }
+
+    /// Same as [`DeploymentRecord::fetch`], reading the code hash through `gateway`.
+    pub async fn fetch_from<G: GatewayAsyncService>(
+        gateway: &G,
+        address: Bech32Address,
+        deployer: Bech32Address,
+        tx_hash: &[u8],
+        abi_version: &str,
+    ) -> Result<Self, RegistryError> {
+        let account = gateway
+            .request(GetAccountRequest::new(address.as_address()))
+            .await
+            .map_err(|err| {
+                RegistryError::Gateway(format!("cannot fetch account {address}: {err}"))
+            })?;
+        let code_hash = match account.code_hash {
+            Some(code_hash) => hex::encode(STANDARD.decode(code_hash)?),
+            None => String::new(),
+        };
+
+        Ok(DeploymentRecord {
+            address,
+            code_hash,
+            deployer,
+            tx_hash: hex::encode(tx_hash),
+            timestamp: now(),
+            abi_version: abi_version.to_owned(),
+        })
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
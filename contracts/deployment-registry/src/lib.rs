@@ -0,0 +1,128 @@
+//! Deployment registry shared by the contract interactors.
+//!
+//! Every deploy and upgrade done by an interactor is recorded in
+//! `contracts/deployments.toml`, keyed by network name and contract name:
+//!
+//! ```toml
+//! [localnet.adder]
+//! address = "erd1qqqqqqqqqqqqqpgq..."
+//! code_hash = "5a1f..."
+//! deployer = "erd1..."
+//! tx_hash = "9c3e..."
+//! timestamp = 1760536800
+//! abi_version = "0.1.0"
+//! ```
+//!
+//! Interactors and tests look addresses up here instead of relying on
+//! whatever `build-deploy.sh` printed last.
+
+mod deployment_record;
+mod registry_error;
+
+pub use deployment_record::DeploymentRecord;
+pub use registry_error::RegistryError;
+
+use multiversx_sc_snippets::{
+    imports::Bech32Address, multiversx_sc_scenario::meta::tools::find_current_workspace,
+};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Registry file name, placed at the root of the contracts workspace.
+pub const REGISTRY_FILE: &str = "deployments.toml";
+
+type NetworkDeployments = BTreeMap<String, DeploymentRecord>;
+
+#[derive(Debug, Default)]
+pub struct DeploymentRegistry {
+    path: PathBuf,
+    networks: BTreeMap<String, NetworkDeployments>,
+}
+
+impl DeploymentRegistry {
+    /// Path of the registry in the contracts workspace the current directory belongs to.
+    pub fn default_path() -> Result<PathBuf, RegistryError> {
+        find_current_workspace()
+            .map(|workspace| workspace.join(REGISTRY_FILE))
+            .ok_or(RegistryError::NoWorkspace)
+    }
+
+    /// Loads the registry from its default location.
+    pub fn load_default() -> Result<Self, RegistryError> {
+        Self::load(Self::default_path()?)
+    }
+
+    /// Loads the registry from `path`; a missing file is an empty registry.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, RegistryError> {
+        let path = path.as_ref().to_path_buf();
+        let networks = match fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(DeploymentRegistry { path, networks })
+    }
+
+    /// Writes the registry back to the file it was loaded from.
+    pub fn save(&self) -> Result<(), RegistryError> {
+        fs::write(&self.path, toml::to_string(&self.networks)?)?;
+        Ok(())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Stores `record` for `contract` on `network`, replacing any previous one.
+    pub fn record(&mut self, network: &str, contract: &str, record: DeploymentRecord) {
+        self.networks
+            .entry(network.to_owned())
+            .or_default()
+            .insert(contract.to_owned(), record);
+    }
+
+    /// Stores `record` and saves the registry.
+    pub fn record_and_save(
+        &mut self,
+        network: &str,
+        contract: &str,
+        record: DeploymentRecord,
+    ) -> Result<(), RegistryError> {
+        self.record(network, contract, record);
+        self.save()
+    }
+
+    pub fn get(&self, network: &str, contract: &str) -> Option<&DeploymentRecord> {
+        self.networks.get(network)?.get(contract)
+    }
+
+    /// Address of `contract` on `network`, or an error telling the user to deploy it first.
+    pub fn address(&self, network: &str, contract: &str) -> Result<&Bech32Address, RegistryError> {
+        self.get(network, contract)
+            .map(|record| &record.address)
+            .ok_or_else(|| RegistryError::NotDeployed {
+                network: network.to_owned(),
+                contract: contract.to_owned(),
+            })
+    }
+
+    /// All contracts recorded on `network`, sorted by contract name.
+    pub fn deployments(&self, network: &str) -> impl Iterator<Item = (&str, &DeploymentRecord)> {
+        self.networks
+            .get(network)
+            .into_iter()
+            .flat_map(|contracts| {
+                contracts
+                    .iter()
+                    .map(|(name, record)| (name.as_str(), record))
+            })
+    }
+
+    pub fn networks(&self) -> impl Iterator<Item = &str> {
+        self.networks.keys().map(String::as_str)
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum RegistryError {
+    Io(std::io::Error),
+    Parse(toml::de::Error),
+    Serialize(toml::ser::Error),
+    /// The current directory is not inside the contracts workspace.
+    NoWorkspace,
+    /// The gateway request for the deployed account failed.
+    Gateway(String),
+    /// The gateway returned a code hash that is not valid base64.
+    CodeHash(base64::DecodeError),
+    NotDeployed {
+        network: String,
+        contract: String,
+    },
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::Io(err) => write!(f, "cannot access deployment registry: {err}"),
+            RegistryError::Parse(err) => write!(f, "invalid deployment registry: {err}"),
+            RegistryError::Serialize(err) => {
+                write!(f, "cannot serialize deployment registry: {err}")
+            }
+            RegistryError::NoWorkspace => {
+                write!(f, "not inside the contracts workspace")
+            }
+            RegistryError::Gateway(err) => write!(f, "gateway error: {err}"),
+            RegistryError::CodeHash(err) => write!(f, "invalid code hash from gateway: {err}"),
+            RegistryError::NotDeployed { network, contract } => {
+                write!(
+                    f,
+                    "no {contract} deployment recorded on {network}, deploy first"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+impl From<std::io::Error> for RegistryError {
+    fn from(err: std::io::Error) -> Self {
+        RegistryError::Io(err)
+    }
+}
+
+impl From<toml::de::Error> for RegistryError {
+    fn from(err: toml::de::Error) -> Self {
+        RegistryError::Parse(err)
+    }
+}
+
+impl From<toml::ser::Error> for RegistryError {
+    fn from(err: toml::ser::Error) -> Self {
+        RegistryError::Serialize(err)
+    }
+}
+
+impl From<base64::DecodeError> for RegistryError {
+    fn from(err: base64::DecodeError) -> Self {
+        RegistryError::CodeHash(err)
+    }
+}
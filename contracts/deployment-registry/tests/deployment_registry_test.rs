@@ -0,0 +1,130 @@
+use deployment_registry::{DeploymentRecord, DeploymentRegistry, RegistryError};
+use multiversx_sc_snippets::imports::*;
+use std::path::PathBuf;
+
+fn temp_registry_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "deployment-registry-{}-{name}.toml",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn record(address: &Address, abi_version: &str) -> DeploymentRecord {
+    DeploymentRecord {
+        address: address.into(),
+        code_hash: "ab".repeat(32),
+        deployer: test_wallets::alice().to_address().into(),
+        tx_hash: "cd".repeat(32),
+        timestamp: 1_760_536_800,
+        abi_version: abi_version.to_owned(),
+    }
+}
+
+#[test]
+fn registry_missing_file_is_empty() {
+    let registry = DeploymentRegistry::load(temp_registry_path("missing")).unwrap();
+
+    assert!(registry.get("localnet", "adder").is_none());
+    assert_eq!(registry.networks().count(), 0);
+}
+
+#[test]
+fn registry_round_trip() {
+    let path = temp_registry_path("round-trip");
+    let adder_localnet = record(&Address::from([1u8; 32]), "0.1.0");
+    let adder_simulator = record(&Address::from([2u8; 32]), "0.1.0");
+    let counter_localnet = record(&Address::from([3u8; 32]), "0.2.0");
+
+    let mut registry = DeploymentRegistry::load(&path).unwrap();
+    registry.record("localnet", "adder", adder_localnet.clone());
+    registry.record("localnet", "counter", counter_localnet.clone());
+    registry.record("chain-simulator", "adder", adder_simulator.clone());
+    registry.save().unwrap();
+
+    let reloaded = DeploymentRegistry::load(&path).unwrap();
+    assert_eq!(reloaded.get("localnet", "adder"), Some(&adder_localnet));
+    assert_eq!(
+        reloaded.get("chain-simulator", "adder"),
+        Some(&adder_simulator)
+    );
+    assert_eq!(
+        reloaded.address("localnet", "counter").unwrap(),
+        &counter_localnet.address
+    );
+    assert_eq!(
+        reloaded
+            .deployments("localnet")
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>(),
+        vec!["adder", "counter"]
+    );
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains("[localnet.adder]"));
+    assert!(content.contains(&adder_localnet.address.to_bech32_string()));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn registry_record_replaces_previous() {
+    let path = temp_registry_path("replace");
+    let address = Address::from([7u8; 32]);
+
+    let mut registry = DeploymentRegistry::load(&path).unwrap();
+    registry
+        .record_and_save("localnet", "adder", record(&address, "0.1.0"))
+        .unwrap();
+    registry
+        .record_and_save("localnet", "adder", record(&address, "0.2.0"))
+        .unwrap();
+
+    let reloaded = DeploymentRegistry::load(&path).unwrap();
+    assert_eq!(
+        reloaded.get("localnet", "adder").unwrap().abi_version,
+        "0.2.0"
+    );
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn registry_unknown_contract() {
+    let registry = DeploymentRegistry::load(temp_registry_path("unknown")).unwrap();
+
+    let err = registry.address("localnet", "adder").unwrap_err();
+    assert!(matches!(err, RegistryError::NotDeployed { .. }));
+    assert_eq!(
+        err.to_string(),
+        "no adder deployment recorded on localnet, deploy first"
+    );
+}
+
+#[test]
+fn registry_default_path_in_workspace() {
+    let path = DeploymentRegistry::default_path().unwrap();
+
+    assert!(path.ends_with("contracts/deployments.toml"));
+}
+
+#[tokio::test]
+async fn record_fetch_unreachable_gateway_is_an_error() {
+    // Nothing listens on port 1, so the account request fails.
+    let gateway = GatewayHttpProxy::new("http://127.0.0.1:1".to_owned());
+    let address: Bech32Address = Address::from([1u8; 32]).into();
+
+    let err = DeploymentRecord::fetch_from(
+        &gateway,
+        address.clone(),
+        test_wallets::alice().to_address().into(),
+        &[0xcd; 32],
+        "0.1.0",
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(err, RegistryError::Gateway(_)));
+    assert!(err.to_string().contains(&address.to_bech32_string()));
+}
@@ -0,0 +1,49 @@
+use crate::{DecodeError, DecodedEvent};
+use multiversx_sc_snippets::imports::{Address, Bech32Address, RustBigUint};
+
+/// Typed view of one event identifier.
+pub trait ContractEvent: Sized {
+    const IDENTIFIER: &'static str;
+
+    fn from_event(event: &DecodedEvent) -> Result<Self, DecodeError>;
+}
+
+/// `added`, emitted by `adder` on every `add`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Added {
+    pub caller: Bech32Address,
+    pub value: RustBigUint,
+    pub new_sum: RustBigUint,
+}
+
+impl ContractEvent for Added {
+    const IDENTIFIER: &'static str = "added";
+
+    fn from_event(event: &DecodedEvent) -> Result<Self, DecodeError> {
+        Ok(Added {
+            caller: event.decode_field::<Address>("caller", "Address")?.into(),
+            value: event.decode_field("value", "BigUint")?,
+            new_sum: event.decode_field("new_sum", "BigUint")?,
+        })
+    }
+}
+
+/// `counterChanged`, emitted by `counter` on every increment and decrement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CounterChanged {
+    pub caller: Bech32Address,
+    pub old: u64,
+    pub new: u64,
+}
+
+impl ContractEvent for CounterChanged {
+    const IDENTIFIER: &'static str = "counterChanged";
+
+    fn from_event(event: &DecodedEvent) -> Result<Self, DecodeError> {
+        Ok(CounterChanged {
+            caller: event.decode_field::<Address>("caller", "Address")?.into(),
+            old: event.decode_field("old", "u64")?,
+            new: event.decode_field("new", "u64")?,
+        })
+    }
+}
@@ -0,0 +1,91 @@
+use multiversx_sc_snippets::multiversx_sc::codec;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum DecodeError {
+    Io(std::io::Error),
+    Abi(serde_json::Error),
+    Base64(base64::DecodeError),
+    /// The log has fewer topics, or no data, for an input the ABI lists.
+    MissingInput {
+        event: String,
+        input: String,
+    },
+    /// The log has more topics than the ABI lists indexed inputs.
+    ExtraTopics {
+        event: String,
+    },
+    WrongEvent {
+        expected: String,
+        found: String,
+    },
+    UnknownField {
+        event: String,
+        field: String,
+    },
+    TypeMismatch {
+        event: String,
+        field: String,
+        expected: String,
+        found: String,
+    },
+    Value {
+        event: String,
+        field: String,
+        err: codec::DecodeError,
+    },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Io(err) => write!(f, "cannot read ABI: {err}"),
+            DecodeError::Abi(err) => write!(f, "invalid ABI: {err}"),
+            DecodeError::Base64(err) => write!(f, "invalid base64 in log: {err}"),
+            DecodeError::MissingInput { event, input } => {
+                write!(f, "{event} log has no value for {input}")
+            }
+            DecodeError::ExtraTopics { event } => {
+                write!(f, "{event} log has more topics than its ABI")
+            }
+            DecodeError::WrongEvent { expected, found } => {
+                write!(f, "expected a {expected} event, found {found}")
+            }
+            DecodeError::UnknownField { event, field } => {
+                write!(f, "{event} has no field {field}")
+            }
+            DecodeError::TypeMismatch {
+                event,
+                field,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{event}.{field} is {found} in the ABI, expected {expected}"
+            ),
+            DecodeError::Value { event, field, err } => {
+                write!(f, "cannot decode {event}.{field}: {}", err.message_str())
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl From<std::io::Error> for DecodeError {
+    fn from(err: std::io::Error) -> Self {
+        DecodeError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for DecodeError {
+    fn from(err: serde_json::Error) -> Self {
+        DecodeError::Abi(err)
+    }
+}
+
+impl From<base64::DecodeError> for DecodeError {
+    fn from(err: base64::DecodeError) -> Self {
+        DecodeError::Base64(err)
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{ContractEvent, DecodeError};
+use multiversx_sc_snippets::{imports::Bech32Address, multiversx_sc::codec::TopDecode};
+
+/// An event matched against the ABI, its values still top-encoded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedEvent {
+    /// Contract that emitted the event.
+    pub address: Bech32Address,
+    pub identifier: String,
+    /// In ABI order, topics and data interleaved as declared.
+    pub fields: Vec<EventField>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventField {
+    pub name: String,
+    /// Type as written in the ABI, e.g. `BigUint` or `Address`.
+    pub type_name: String,
+    pub value: Vec<u8>,
+}
+
+impl DecodedEvent {
+    pub fn field(&self, name: &str) -> Option<&EventField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Decodes field `name`, after checking that the ABI declares it as `type_name`.
+    pub fn decode_field<T: TopDecode>(
+        &self,
+        name: &str,
+        type_name: &str,
+    ) -> Result<T, DecodeError> {
+        let field = self.field(name).ok_or_else(|| DecodeError::UnknownField {
+            event: self.identifier.clone(),
+            field: name.to_owned(),
+        })?;
+        if field.type_name != type_name {
+            return Err(DecodeError::TypeMismatch {
+                event: self.identifier.clone(),
+                field: name.to_owned(),
+                expected: type_name.to_owned(),
+                found: field.type_name.clone(),
+            });
+        }
+
+        T::top_decode(field.value.as_slice()).map_err(|err| DecodeError::Value {
+            event: self.identifier.clone(),
+            field: name.to_owned(),
+            err,
+        })
+    }
+
+    pub fn is<E: ContractEvent>(&self) -> bool {
+        self.identifier == E::IDENTIFIER
+    }
+
+    pub fn to_typed<E: ContractEvent>(&self) -> Result<E, DecodeError> {
+        if !self.is::<E>() {
+            return Err(DecodeError::WrongEvent {
+                expected: E::IDENTIFIER.to_owned(),
+                found: self.identifier.clone(),
+            });
+        }
+        E::from_event(self)
+    }
+}
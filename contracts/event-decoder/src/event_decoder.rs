@@ -0,0 +1,157 @@
+use crate::{ContractEvent, DecodeError, DecodedEvent, EventField};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use multiversx_sc_snippets::{
+    imports::{Address, Bech32Address},
+    multiversx_sc_scenario::meta::abi_json::{ContractAbiJson, EventAbiJson},
+    sdk::data::transaction::{ApiLogs, Events, LogData, TransactionOnNetwork},
+};
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// Decodes the events of one contract, as described by its ABI.
+#[derive(Clone, Debug, Default)]
+pub struct EventDecoder {
+    events: BTreeMap<String, Vec<InputLayout>>,
+    address: Option<Address>,
+}
+
+#[derive(Clone, Debug)]
+struct InputLayout {
+    name: String,
+    type_name: String,
+    indexed: bool,
+}
+
+impl EventDecoder {
+    pub fn new(abi: &ContractAbiJson) -> Self {
+        let events = abi
+            .events
+            .iter()
+            .map(|event| (event.identifier.clone(), input_layouts(event)))
+            .collect();
+        EventDecoder {
+            events,
+            address: None,
+        }
+    }
+
+    pub fn from_abi_json(abi_json: &str) -> Result<Self, DecodeError> {
+        let abi: ContractAbiJson = serde_json::from_str(abi_json)?;
+        Ok(Self::new(&abi))
+    }
+
+    /// Loads a `<contract>.abi.json` file, as written by `sc-meta all build` to `output/`.
+    pub fn from_abi_file<P: AsRef<Path>>(path: P) -> Result<Self, DecodeError> {
+        Self::from_abi_json(&fs::read_to_string(path)?)
+    }
+
+    /// Only decodes events emitted by `address`. Without it, another contract's event
+    /// with a matching identifier is decoded too, or fails if its layout differs.
+    pub fn with_address(mut self, address: impl Into<Address>) -> Self {
+        self.address = Some(address.into());
+        self
+    }
+
+    /// Decodes one log event. Returns `None` for events not described by the ABI,
+    /// or emitted by another address than the one set with [`Self::with_address`].
+    pub fn decode_event(&self, event: &Events) -> Result<Option<DecodedEvent>, DecodeError> {
+        if self
+            .address
+            .as_ref()
+            .is_some_and(|address| *address != event.address.0)
+        {
+            return Ok(None);
+        }
+
+        let mut topics = event.topics.iter().flatten();
+        let Some(first_topic) = topics.next() else {
+            return Ok(None);
+        };
+        let Ok(identifier) = String::from_utf8(STANDARD.decode(first_topic)?) else {
+            return Ok(None);
+        };
+        let Some(inputs) = self.events.get(&identifier) else {
+            return Ok(None);
+        };
+
+        let mut data = first_data(&event.data);
+        let mut fields = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            let value = if input.indexed {
+                topics.next()
+            } else {
+                data.take()
+            };
+            let value = value.ok_or_else(|| DecodeError::MissingInput {
+                event: identifier.clone(),
+                input: input.name.clone(),
+            })?;
+            fields.push(EventField {
+                name: input.name.clone(),
+                type_name: input.type_name.clone(),
+                value: STANDARD.decode(value)?,
+            });
+        }
+        if topics.next().is_some() {
+            return Err(DecodeError::ExtraTopics { event: identifier });
+        }
+
+        Ok(Some(DecodedEvent {
+            address: Bech32Address::from(&event.address.0),
+            identifier,
+            fields,
+        }))
+    }
+
+    /// Decodes the events the ABI describes, in log order.
+    pub fn decode_logs(&self, logs: &ApiLogs) -> Result<Vec<DecodedEvent>, DecodeError> {
+        let mut decoded = Vec::new();
+        for event in &logs.events {
+            decoded.extend(self.decode_event(event)?);
+        }
+        Ok(decoded)
+    }
+
+    pub fn decode_transaction(
+        &self,
+        tx: &TransactionOnNetwork,
+    ) -> Result<Vec<DecodedEvent>, DecodeError> {
+        match &tx.logs {
+            Some(logs) => self.decode_logs(logs),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// The `E` events of a transaction, in log order.
+    pub fn typed_events<E: ContractEvent>(
+        &self,
+        tx: &TransactionOnNetwork,
+    ) -> Result<Vec<E>, DecodeError> {
+        self.decode_transaction(tx)?
+            .iter()
+            .filter(|event| event.is::<E>())
+            .map(DecodedEvent::to_typed)
+            .collect()
+    }
+}
+
+fn input_layouts(event: &EventAbiJson) -> Vec<InputLayout> {
+    event
+        .inputs
+        .iter()
+        .map(|input| InputLayout {
+            name: input.arg_name.clone(),
+            type_name: input.type_name.clone(),
+            indexed: input.indexed.unwrap_or_default(),
+        })
+        .collect()
+}
+
+/// Events have at most one data input. The proxy API returns it as a single
+/// base64 string, or as the first entry of a list.
+fn first_data(data: &LogData) -> Option<&String> {
+    match data {
+        LogData::Empty => None,
+        LogData::String(data) => Some(data),
+        LogData::Vec(data) => data.first(),
+    }
+}
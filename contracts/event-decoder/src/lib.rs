@@ -0,0 +1,26 @@
+//! Decodes contract events out of transaction logs, as returned by the proxy API.
+//!
+//! The contract ABI says, for each event identifier, which inputs are topics
+//! and which one is the data field, and of what type:
+//!
+//! ```ignore
+//! let decoder = EventDecoder::from_abi_file("examples/adder/output/adder.abi.json")?
+//!     .with_address(adder_address);
+//! let tx = interactor.proxy.request(GetTxInfo::new(&tx_hash).with_results()).await?;
+//! for added in decoder.typed_events::<Added>(&tx)? {
+//!     println!("{} added {}, sum is now {}", added.caller, added.value, added.new_sum);
+//! }
+//! ```
+//!
+//! Events the ABI does not describe, such as `transferValueOnly` or
+//! `completedTxEvent`, are skipped.
+
+mod contract_events;
+mod decode_error;
+mod decoded_event;
+mod event_decoder;
+
+pub use contract_events::{Added, ContractEvent, CounterChanged};
+pub use decode_error::DecodeError;
+pub use decoded_event::{DecodedEvent, EventField};
+pub use event_decoder::EventDecoder;
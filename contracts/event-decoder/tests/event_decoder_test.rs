@@ -0,0 +1,235 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use event_decoder::{Added, CounterChanged, DecodeError, EventDecoder};
+use multiversx_sc_snippets::{
+    imports::*,
+    multiversx_sc_scenario::meta::abi_json::contract_abi,
+    sdk::data::{
+        sdk_address::SdkAddress,
+        transaction::{ApiLogs, Events, LogData},
+    },
+};
+
+const ADDER: [u8; 32] = [5u8; 32];
+const COUNTER: [u8; 32] = [6u8; 32];
+const CALLER: [u8; 32] = [1u8; 32];
+
+fn adder_decoder() -> EventDecoder {
+    EventDecoder::from_abi_json(&contract_abi::<adder::AbiProvider>()).unwrap()
+}
+
+fn counter_decoder() -> EventDecoder {
+    EventDecoder::from_abi_json(&contract_abi::<counter::AbiProvider>()).unwrap()
+}
+
+fn b64(bytes: &[u8]) -> String {
+    STANDARD.encode(bytes)
+}
+
+fn event(address: [u8; 32], identifier: &str, topics: &[&[u8]], data: &[u8]) -> Events {
+    Events {
+        address: SdkAddress(Address::from(address)),
+        identifier: identifier.to_owned(),
+        topics: Some(topics.iter().map(|topic| b64(topic)).collect()),
+        data: LogData::String(b64(data)),
+    }
+}
+
+fn logs(events: Vec<Events>) -> ApiLogs {
+    ApiLogs {
+        address: SdkAddress(Address::from(CALLER)),
+        events,
+    }
+}
+
+#[test]
+fn decodes_added() {
+    let log = event(
+        ADDER,
+        "add",
+        &[b"added", &CALLER, &[0x03, 0xe8]],
+        &[0x03, 0xf0],
+    );
+
+    let decoded = adder_decoder().decode_event(&log).unwrap().unwrap();
+    assert_eq!(decoded.identifier, "added");
+    assert_eq!(decoded.address, Bech32Address::from(Address::from(ADDER)));
+    assert_eq!(
+        decoded.to_typed::<Added>().unwrap(),
+        Added {
+            caller: Address::from(CALLER).into(),
+            value: RustBigUint::from(1_000u32),
+            new_sum: RustBigUint::from(1_008u32),
+        }
+    );
+}
+
+#[test]
+fn decodes_counter_changed_from_zero() {
+    // zero top-encodes as an empty topic
+    let log = event(
+        COUNTER,
+        "increment",
+        &[b"counterChanged", &CALLER, &[]],
+        &[1],
+    );
+
+    let decoded = counter_decoder().decode_event(&log).unwrap().unwrap();
+    assert_eq!(
+        decoded.to_typed::<CounterChanged>().unwrap(),
+        CounterChanged {
+            caller: Address::from(CALLER).into(),
+            old: 0,
+            new: 1,
+        }
+    );
+}
+
+#[test]
+fn skips_events_outside_the_abi() {
+    let decoder = adder_decoder();
+    let logs = logs(vec![
+        event(ADDER, "add", &[b"added", &CALLER, &[2]], &[7]),
+        event(ADDER, "add", &[b"writeLog", &CALLER], b"@6f6b"),
+        event(
+            COUNTER,
+            "increment",
+            &[b"counterChanged", &CALLER, &[]],
+            &[1],
+        ),
+        event(ADDER, "add", &[b"completedTxEvent", &[0xab; 32]], &[]),
+    ]);
+
+    let decoded = decoder.decode_logs(&logs).unwrap();
+    assert_eq!(decoded.len(), 1);
+    assert_eq!(decoded[0].identifier, "added");
+}
+
+#[test]
+fn with_address_ignores_other_contracts() {
+    let decoder = adder_decoder().with_address(Address::from(ADDER));
+    let other_adder = event(COUNTER, "add", &[b"added", &CALLER, &[2]], &[7]);
+
+    assert_eq!(decoder.decode_event(&other_adder).unwrap(), None);
+}
+
+#[test]
+fn decodes_proxy_api_json() {
+    let adder = Bech32Address::from(Address::from(ADDER));
+    let caller = Bech32Address::from(Address::from(CALLER));
+    // newer proxies return the data field as a list
+    let json = format!(
+        r#"{{
+            "address": "{caller}",
+            "events": [
+                {{
+                    "address": "{adder}",
+                    "identifier": "add",
+                    "topics": ["{}", "{}", "{}"],
+                    "data": ["{}"]
+                }},
+                {{
+                    "address": "{caller}",
+                    "identifier": "completedTxEvent",
+                    "topics": ["{}"],
+                    "data": null
+                }}
+            ]
+        }}"#,
+        b64(b"added"),
+        b64(&CALLER),
+        b64(&[5]),
+        b64(&[10]),
+        b64(&[0xab; 32]),
+    );
+    let logs: ApiLogs = serde_json::from_str(&json).unwrap();
+
+    let added: Vec<Added> = adder_decoder()
+        .decode_logs(&logs)
+        .unwrap()
+        .iter()
+        .map(|event| event.to_typed().unwrap())
+        .collect();
+    assert_eq!(
+        added,
+        vec![Added {
+            caller,
+            value: RustBigUint::from(5u32),
+            new_sum: RustBigUint::from(10u32),
+        }]
+    );
+}
+
+#[test]
+fn loads_abi_file() {
+    let path = std::env::temp_dir().join(format!("event-decoder-{}.abi.json", std::process::id()));
+    std::fs::write(&path, contract_abi::<counter::AbiProvider>()).unwrap();
+
+    let decoder = EventDecoder::from_abi_file(&path).unwrap();
+    let log = event(
+        COUNTER,
+        "decrement",
+        &[b"counterChanged", &CALLER, &[3]],
+        &[2],
+    );
+    let changed: CounterChanged = decoder
+        .decode_event(&log)
+        .unwrap()
+        .unwrap()
+        .to_typed()
+        .unwrap();
+    assert_eq!((changed.old, changed.new), (3, 2));
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn missing_topic_is_an_error() {
+    let log = event(ADDER, "add", &[b"added", &CALLER], &[7]);
+
+    assert!(matches!(
+        adder_decoder().decode_event(&log),
+        Err(DecodeError::MissingInput { input, .. }) if input == "value"
+    ));
+}
+
+#[test]
+fn extra_topic_is_an_error() {
+    let log = event(ADDER, "add", &[b"added", &CALLER, &[2], &[3]], &[7]);
+
+    assert!(matches!(
+        adder_decoder().decode_event(&log),
+        Err(DecodeError::ExtraTopics { .. })
+    ));
+}
+
+#[test]
+fn wrong_typed_event_is_an_error() {
+    let log = event(ADDER, "add", &[b"added", &CALLER, &[2]], &[7]);
+    let decoded = adder_decoder().decode_event(&log).unwrap().unwrap();
+
+    assert!(matches!(
+        decoded.to_typed::<CounterChanged>(),
+        Err(DecodeError::WrongEvent { found, .. }) if found == "added"
+    ));
+    assert!(matches!(
+        decoded.decode_field::<u64>("value", "u64"),
+        Err(DecodeError::TypeMismatch { found, .. }) if found == "BigUint"
+    ));
+}
+
+#[test]
+fn invalid_value_is_an_error() {
+    // an address one byte short
+    let log = event(
+        COUNTER,
+        "increment",
+        &[b"counterChanged", &CALLER[1..], &[]],
+        &[1],
+    );
+    let decoded = counter_decoder().decode_event(&log).unwrap().unwrap();
+
+    assert!(matches!(
+        decoded.to_typed::<CounterChanged>(),
+        Err(DecodeError::Value { field, .. }) if field == "caller"
+    ));
+}
@@ -0,0 +1,75 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod adder_caller_proxy;
+pub mod adder_proxy;
+
+/// Calls the `Adder` contract through its generated proxy,
+/// once synchronously and once through an async call with a callback.
+#[multiversx_sc::contract]
+pub trait AdderCaller {
+    #[init]
+    fn init(&self, adder_address: ManagedAddress) {
+        self.adder_address().set(adder_address);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Adds `value` on the same shard and returns the new sum read back from `Adder`.
+    #[endpoint(addSync)]
+    fn add_sync(&self, value: BigUint) -> BigUint {
+        let adder_address = self.adder_address().get();
+
+        self.tx()
+            .to(&adder_address)
+            .typed(adder_proxy::AdderProxy)
+            .add(value)
+            .sync_call();
+
+        self.tx()
+            .to(&adder_address)
+            .typed(adder_proxy::AdderProxy)
+            .sum()
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+    }
+
+    /// Adds `value` through an async call; the outcome is recorded in the callback.
+    #[endpoint(addAsync)]
+    fn add_async(&self, value: BigUint) {
+        let adder_address = self.adder_address().get();
+
+        self.tx()
+            .to(&adder_address)
+            .typed(adder_proxy::AdderProxy)
+            .add(&value)
+            .callback(self.callbacks().add_callback(value))
+            .async_call_and_exit();
+    }
+
+    #[callback]
+    fn add_callback(&self, value: BigUint, #[call_result] result: ManagedAsyncCallResult<()>) {
+        match result {
+            ManagedAsyncCallResult::Ok(()) => {
+                self.async_added().update(|total| *total += value);
+            }
+            ManagedAsyncCallResult::Err(err) => {
+                self.last_async_error().set(err.err_msg);
+            }
+        }
+    }
+
+    #[view(getAdderAddress)]
+    #[storage_mapper("adderAddress")]
+    fn adder_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getAsyncAdded)]
+    #[storage_mapper("asyncAdded")]
+    fn async_added(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getLastAsyncError)]
+    #[storage_mapper("lastAsyncError")]
+    fn last_async_error(&self) -> SingleValueMapper<ManagedBuffer>;
+}
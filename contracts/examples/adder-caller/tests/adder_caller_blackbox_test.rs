@@ -0,0 +1,106 @@
+use multiversx_sc_scenario::imports::*;
+
+use adder_caller::{adder_caller_proxy, adder_proxy};
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const ADDER_ADDRESS: TestSCAddress = TestSCAddress::new("adder");
+const CALLER_ADDRESS: TestSCAddress = TestSCAddress::new("adder-caller");
+const ADDER_CODE_PATH: MxscPath = MxscPath::new("../adder/output/adder.mxsc.json");
+const CALLER_CODE_PATH: MxscPath = MxscPath::new("output/adder-caller.mxsc.json");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/adder-caller");
+    blockchain.register_contract(ADDER_CODE_PATH, adder::ContractBuilder);
+    blockchain.register_contract(CALLER_CODE_PATH, adder_caller::ContractBuilder);
+    blockchain
+}
+
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER_ADDRESS).nonce(1);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .init(5u32)
+        .code(ADDER_CODE_PATH)
+        .new_address(ADDER_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(adder_caller_proxy::AdderCallerProxy)
+        .init(ADDER_ADDRESS)
+        .code(CALLER_CODE_PATH)
+        .new_address(CALLER_ADDRESS)
+        .run();
+
+    world
+}
+
+fn check_adder_sum(world: &mut ScenarioWorld, expected: u64) {
+    world
+        .query()
+        .to(ADDER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .sum()
+        .returns(ExpectValue(expected))
+        .run();
+}
+
+#[test]
+fn adder_caller_sync_call() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(CALLER_ADDRESS)
+        .typed(adder_caller_proxy::AdderCallerProxy)
+        .add_sync(10u32)
+        .returns(ExpectValue(15u32))
+        .run();
+
+    check_adder_sum(&mut world, 15);
+}
+
+#[test]
+fn adder_caller_async_call() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(CALLER_ADDRESS)
+        .typed(adder_caller_proxy::AdderCallerProxy)
+        .add_async(7u32)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(CALLER_ADDRESS)
+        .typed(adder_caller_proxy::AdderCallerProxy)
+        .add_async(3u32)
+        .run();
+
+    check_adder_sum(&mut world, 15);
+
+    world
+        .query()
+        .to(CALLER_ADDRESS)
+        .typed(adder_caller_proxy::AdderCallerProxy)
+        .async_added()
+        .returns(ExpectValue(10u32))
+        .run();
+    world
+        .query()
+        .to(CALLER_ADDRESS)
+        .typed(adder_caller_proxy::AdderCallerProxy)
+        .last_async_error()
+        .returns(ExpectValue(ManagedBuffer::<StaticApi>::new()))
+        .run();
+}
@@ -0,0 +1,31 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            5
+// Async Callback:                       1
+// Total number of exported functions:   8
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    adder_caller
+    (
+        init => init
+        upgrade => upgrade
+        addSync => add_sync
+        addAsync => add_async
+        getAdderAddress => adder_address
+        getAsyncAdded => async_added
+        getLastAsyncError => last_async_error
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback! { adder_caller }
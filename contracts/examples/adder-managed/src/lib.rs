@@ -0,0 +1,72 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod adder_managed_proxy;
+
+use multiversx_sc_modules::{only_admin, pause};
+
+pub const ERR_ZERO_MAX_PER_CALL: &str = "max per call cannot be zero";
+pub const ERR_CALLER_NOT_ALLOWED: &str = "caller is not allowed to add";
+pub const ERR_ABOVE_MAX_PER_CALL: &str = "value exceeds the max per call";
+
+/// `adder` with access control. The owner pauses the contract and appoints admins; admins
+/// decide who may call `add` and how much a single call may add.
+#[multiversx_sc::contract]
+pub trait AdderManaged: pause::PauseModule + only_admin::OnlyAdminModule {
+    #[init]
+    fn init(&self, initial_value: BigUint, max_per_call: BigUint) {
+        self.sum().set(initial_value);
+        self.set_max_per_call(max_per_call);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[endpoint]
+    fn add(&self, value: BigUint) {
+        self.require_not_paused();
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.allowed_callers().contains(&caller),
+            ERR_CALLER_NOT_ALLOWED
+        );
+        require!(value <= self.max_per_call().get(), ERR_ABOVE_MAX_PER_CALL);
+
+        self.sum().update(|sum| *sum += value);
+    }
+
+    #[only_admin]
+    #[endpoint(setMaxPerCall)]
+    fn set_max_per_call(&self, max_per_call: BigUint) {
+        require!(max_per_call > 0, ERR_ZERO_MAX_PER_CALL);
+        self.max_per_call().set(max_per_call);
+    }
+
+    #[only_admin]
+    #[endpoint(allowCallers)]
+    fn allow_callers(&self, callers: MultiValueEncoded<ManagedAddress>) {
+        self.allowed_callers().extend(callers);
+    }
+
+    #[only_admin]
+    #[endpoint(disallowCallers)]
+    fn disallow_callers(&self, callers: MultiValueEncoded<ManagedAddress>) {
+        let mut allowed_callers = self.allowed_callers();
+        for caller in callers {
+            allowed_callers.swap_remove(&caller);
+        }
+    }
+
+    #[view(getSum)]
+    #[storage_mapper("sum")]
+    fn sum(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getMaxPerCall)]
+    #[storage_mapper("maxPerCall")]
+    fn max_per_call(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getAllowedCallers)]
+    #[storage_mapper("allowedCallers")]
+    fn allowed_callers(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
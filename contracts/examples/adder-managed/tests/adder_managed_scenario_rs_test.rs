@@ -0,0 +1,26 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/adder-managed");
+    blockchain.register_contract(
+        "mxsc:output/adder-managed.mxsc.json",
+        adder_managed::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn adder_managed_access_rs() {
+    world().run("scenarios/adder-managed-access.scen.json");
+}
+
+#[test]
+fn adder_managed_pause_rs() {
+    world().run("scenarios/adder-managed-pause.scen.json");
+}
+
+#[test]
+fn adder_managed_setup_rs() {
+    world().run("scenarios/adder-managed-setup.scen.json");
+}
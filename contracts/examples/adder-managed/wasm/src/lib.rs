@@ -0,0 +1,40 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           14
+// Async Callback (empty):               1
+// Total number of exported functions:  17
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    adder_managed
+    (
+        init => init
+        upgrade => upgrade
+        add => add
+        setMaxPerCall => set_max_per_call
+        allowCallers => allow_callers
+        disallowCallers => disallow_callers
+        getSum => sum
+        getMaxPerCall => max_per_call
+        getAllowedCallers => allowed_callers
+        pause => pause_endpoint
+        unpause => unpause_endpoint
+        isPaused => paused_status
+        isAdmin => is_admin
+        addAdmin => add_admin
+        removeAdmin => remove_admin
+        getAdmins => admins
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
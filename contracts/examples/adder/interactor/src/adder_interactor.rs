@@ -0,0 +1,193 @@
+mod adder_interactor_cli;
+mod adder_interactor_config;
+
+use adder::adder_proxy;
+pub use adder_interactor_config::Config;
+use clap::Parser;
+use deployment_registry::{DeploymentRecord, DeploymentRegistry, RegistryError};
+
+use multiversx_sc_snippets::{imports::*, multiversx_sc::contract_base::ContractAbiProvider};
+
+const CONTRACT_NAME: &str = "adder";
+const ADDER_CODE_PATH: MxscPath = MxscPath::new("../output/adder.mxsc.json");
+
+pub async fn adder_cli() {
+    env_logger::init();
+
+    let cli = adder_interactor_cli::InteractCli::parse();
+    let mut config = Config::load_config();
+    if let Some(gateway) = cli.gateway {
+        config.gateway_uri = gateway;
+    }
+    if let Some(pem) = cli.pem {
+        config.owner_pem = Some(pem);
+    }
+
+    let mut interact = AdderInteract::new(config).await;
+    if let Err(err) = run_command(&mut interact, &cli.command).await {
+        println!("{err}");
+    }
+}
+
+async fn run_command(
+    interact: &mut AdderInteract,
+    command: &Option<adder_interactor_cli::InteractCliCommand>,
+) -> Result<(), RegistryError> {
+    match command {
+        Some(adder_interactor_cli::InteractCliCommand::Deploy(args)) => {
+            interact.deploy(args.initial_value).await;
+        }
+        Some(adder_interactor_cli::InteractCliCommand::Upgrade) => {
+            interact.upgrade().await?;
+        }
+        Some(adder_interactor_cli::InteractCliCommand::Add(args)) => {
+            interact.add(args.value).await?;
+        }
+        Some(adder_interactor_cli::InteractCliCommand::Sum) => {
+            let sum = interact.get_sum().await?;
+            println!("sum: {sum}");
+        }
+        None => {}
+    }
+
+    Ok(())
+}
+
+pub struct AdderInteract {
+    pub interactor: Interactor,
+    pub owner_address: Bech32Address,
+    pub network: String,
+    pub registry: DeploymentRegistry,
+}
+
+impl AdderInteract {
+    pub async fn new(config: Config) -> Self {
+        let mut interactor = Interactor::new(&config.gateway_uri)
+            .await
+            .use_chain_simulator(config.use_chain_simulator());
+        interactor.set_current_dir_from_workspace("examples/adder/interactor");
+
+        let owner_address = interactor.register_wallet(config.owner_wallet()).await;
+
+        // generate blocks until ESDTSystemSCActivationEpoch in order to be able to use the chain simulator
+        interactor.generate_blocks_until_epoch(1).await.unwrap();
+
+        let registry = DeploymentRegistry::load_default().unwrap_or_else(|err| {
+            println!("deployment registry not loaded: {err}");
+            DeploymentRegistry::default()
+        });
+
+        AdderInteract {
+            interactor,
+            owner_address: owner_address.into(),
+            network: config.network,
+            registry,
+        }
+    }
+
+    pub async fn deploy(&mut self, initial_value: u64) {
+        let (new_address, tx_hash) = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .gas(5_000_000)
+            .typed(adder_proxy::AdderProxy)
+            .init(initial_value)
+            .code(ADDER_CODE_PATH)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .returns(ReturnsNewBech32Address)
+            .returns(ReturnsTxHash)
+            .run()
+            .await;
+
+        println!("new address: {new_address}");
+        if let Err(err) = self
+            .record_deployment(new_address, tx_hash.as_bytes())
+            .await
+        {
+            println!("deployment not recorded in the registry: {err}");
+        }
+    }
+
+    pub async fn upgrade(&mut self) -> Result<(), RegistryError> {
+        let adder_address = self.adder_address()?;
+        let tx_hash = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&adder_address)
+            .gas(5_000_000)
+            .typed(adder_proxy::AdderProxy)
+            .upgrade()
+            .code(ADDER_CODE_PATH)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .returns(ReturnsTxHash)
+            .run()
+            .await;
+
+        println!("upgraded {adder_address}");
+        if let Err(err) = self
+            .record_deployment(adder_address, tx_hash.as_bytes())
+            .await
+        {
+            println!("deployment not recorded in the registry: {err}");
+        }
+
+        Ok(())
+    }
+
+    pub async fn add(&mut self, value: u64) -> Result<(), RegistryError> {
+        let adder_address = self.adder_address()?;
+        self.interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&adder_address)
+            .gas(5_000_000)
+            .typed(adder_proxy::AdderProxy)
+            .add(value)
+            .run()
+            .await;
+
+        println!("added {value}");
+
+        Ok(())
+    }
+
+    pub async fn get_sum(&mut self) -> Result<RustBigUint, RegistryError> {
+        let adder_address = self.adder_address()?;
+        let sum = self
+            .interactor
+            .query()
+            .to(&adder_address)
+            .typed(adder_proxy::AdderProxy)
+            .sum()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+        Ok(sum)
+    }
+
+    /// Address of the adder contract recorded for the configured network.
+    pub fn adder_address(&self) -> Result<Bech32Address, RegistryError> {
+        self.registry.address(&self.network, CONTRACT_NAME).cloned()
+    }
+
+    async fn record_deployment(
+        &mut self,
+        address: Bech32Address,
+        tx_hash: &[u8],
+    ) -> Result<(), RegistryError> {
+        let abi_version = adder::AbiProvider::abi().build_info.contract_crate.version;
+        let record = DeploymentRecord::fetch(
+            &self.interactor,
+            address,
+            self.owner_address.clone(),
+            tx_hash,
+            abi_version,
+        )
+        .await?;
+
+        self.registry
+            .record_and_save(&self.network, CONTRACT_NAME, record)
+    }
+}
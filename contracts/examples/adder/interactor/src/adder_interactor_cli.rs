@@ -0,0 +1,44 @@
+use clap::{Args, Parser, Subcommand};
+
+/// Adder Interact CLI
+#[derive(Default, PartialEq, Eq, Debug, Parser)]
+#[command(version, about)]
+#[command(propagate_version = true)]
+pub struct InteractCli {
+    /// Overrides `gateway_uri` from config.toml
+    #[arg(long, global = true)]
+    pub gateway: Option<String>,
+
+    /// Overrides `owner_pem` from config.toml
+    #[arg(long, global = true)]
+    pub pem: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<InteractCliCommand>,
+}
+
+/// Adder Interact CLI Commands
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum InteractCliCommand {
+    #[command(name = "deploy", about = "Deploy contract")]
+    Deploy(DeployArgs),
+    #[command(name = "upgrade", about = "Upgrade contract")]
+    Upgrade,
+    #[command(name = "add", about = "Add value")]
+    Add(AddArgs),
+    #[command(name = "sum", about = "Print sum")]
+    Sum,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct DeployArgs {
+    /// Initial value of the sum
+    #[arg(default_value_t = 0)]
+    pub initial_value: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AddArgs {
+    /// The value to add
+    pub value: u64,
+}
@@ -0,0 +1,6 @@
+use multiversx_sc_snippets::imports::*;
+
+#[tokio::main]
+async fn main() {
+    adder_interactor::adder_cli().await;
+}
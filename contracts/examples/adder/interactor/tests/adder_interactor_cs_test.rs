@@ -0,0 +1,25 @@
+use adder_interactor::{AdderInteract, Config};
+use multiversx_sc_snippets::imports::*;
+
+#[tokio::test]
+#[cfg_attr(not(feature = "chain-simulator-tests"), ignore)]
+async fn adder_simulator_deploy_add_upgrade() {
+    let mut interact = AdderInteract::new(Config::chain_simulator_config()).await;
+
+    interact.deploy(5).await;
+    let deployed = interact
+        .registry
+        .get("chain-simulator", "adder")
+        .expect("deployment not recorded")
+        .clone();
+    assert_eq!(deployed.abi_version, "0.1.0");
+    interact.add(10).await.unwrap();
+    assert_eq!(interact.get_sum().await.unwrap(), RustBigUint::from(15u32));
+
+    interact.upgrade().await.unwrap();
+    assert_eq!(interact.adder_address().unwrap(), deployed.address);
+    assert_eq!(interact.get_sum().await.unwrap(), RustBigUint::from(15u32));
+
+    interact.add(1).await.unwrap();
+    assert_eq!(interact.get_sum().await.unwrap(), RustBigUint::from(16u32));
+}
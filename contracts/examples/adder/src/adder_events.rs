@@ -0,0 +1,13 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait AdderEventsModule {
+    /// Emitted by `add`, with the sum after the addition.
+    #[event("added")]
+    fn added_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] value: &BigUint,
+        new_sum: &BigUint,
+    );
+}
@@ -2,8 +2,11 @@
 
 multiversx_sc::imports!();
 
+pub mod adder_events;
+pub mod adder_proxy;
+
 #[multiversx_sc::contract]
-pub trait Adder {
+pub trait Adder: adder_events::AdderEventsModule {
     #[init]
     fn init(&self, initial_value: BigUint) {
         self.sum().set(initial_value);
@@ -14,7 +17,11 @@ pub trait Adder {
 
     #[endpoint]
     fn add(&self, value: BigUint) {
-        self.sum().update(|sum| *sum += value);
+        let new_sum = self.sum().update(|sum| {
+            *sum += &value;
+            sum.clone()
+        });
+        self.added_event(&self.blockchain().get_caller(), &value, &new_sum);
     }
 
     #[view(getSum)]
@@ -0,0 +1,146 @@
+use multiversx_sc_scenario::imports::*;
+
+use adder::adder_proxy;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const USER_ADDRESSES: [TestAddress; 3] = [
+    TestAddress::new("user1"),
+    TestAddress::new("user2"),
+    TestAddress::new("user3"),
+];
+const ADDER_ADDRESS: TestSCAddress = TestSCAddress::new("adder");
+const CODE_PATH: MxscPath = MxscPath::new("output/adder.mxsc.json");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/adder");
+    blockchain.register_contract(CODE_PATH, adder::ContractBuilder);
+    blockchain
+}
+
+struct AdderTestState {
+    world: ScenarioWorld,
+}
+
+impl AdderTestState {
+    fn new() -> Self {
+        let mut world = world();
+
+        world.account(OWNER_ADDRESS).nonce(1);
+        for user in USER_ADDRESSES {
+            world.account(user).nonce(1);
+        }
+
+        Self { world }
+    }
+
+    fn deploy(&mut self, initial_value: u64) -> &mut Self {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(adder_proxy::AdderProxy)
+            .init(initial_value)
+            .code(CODE_PATH)
+            .new_address(ADDER_ADDRESS)
+            .returns(ReturnsNewAddress)
+            .run();
+
+        self
+    }
+
+    fn add(&mut self, from: TestAddress, value: u64) -> &mut Self {
+        self.world
+            .tx()
+            .from(from)
+            .to(ADDER_ADDRESS)
+            .typed(adder_proxy::AdderProxy)
+            .add(value)
+            .run();
+
+        self
+    }
+
+    fn upgrade(&mut self) -> &mut Self {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ADDER_ADDRESS)
+            .typed(adder_proxy::AdderProxy)
+            .upgrade()
+            .code(CODE_PATH)
+            .run();
+
+        self
+    }
+
+    fn check_sum(&mut self, expected: u64) -> &mut Self {
+        self.world
+            .query()
+            .to(ADDER_ADDRESS)
+            .typed(adder_proxy::AdderProxy)
+            .sum()
+            .returns(ExpectValue(expected))
+            .run();
+
+        self
+    }
+}
+
+#[test]
+fn adder_blackbox_deploy_initial_values() {
+    for initial_value in [0u64, 1, 5, 1_000_000, u64::MAX] {
+        let mut state = AdderTestState::new();
+        state.deploy(initial_value).check_sum(initial_value);
+    }
+}
+
+#[test]
+fn adder_blackbox_add_from_many_accounts() {
+    let mut state = AdderTestState::new();
+    state.deploy(5);
+
+    let mut expected = 5u64;
+    for round in 1..=10u64 {
+        for user in USER_ADDRESSES {
+            state.add(user, round);
+            expected += round;
+        }
+    }
+    state.add(OWNER_ADDRESS, 7);
+    expected += 7;
+
+    state.check_sum(expected);
+}
+
+#[test]
+fn adder_blackbox_add_beyond_u64() {
+    let mut state = AdderTestState::new();
+    state.deploy(u64::MAX).add(USER_ADDRESSES[0], 1);
+
+    state
+        .world
+        .query()
+        .to(ADDER_ADDRESS)
+        .typed(adder_proxy::AdderProxy)
+        .sum()
+        .returns(ExpectValue(BigUint::<StaticApi>::from(1u32) << 64))
+        .run();
+}
+
+#[test]
+fn adder_blackbox_upgrade_keeps_sum() {
+    let mut state = AdderTestState::new();
+    state
+        .deploy(5)
+        .add(USER_ADDRESSES[0], 10)
+        .add(USER_ADDRESSES[1], 20)
+        .check_sum(35);
+
+    state.upgrade().check_sum(35);
+    state
+        .world
+        .check_account(ADDER_ADDRESS)
+        .check_storage("str:sum", "35");
+
+    state.add(USER_ADDRESSES[2], 5).check_sum(40);
+}
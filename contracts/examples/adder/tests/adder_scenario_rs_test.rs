@@ -0,0 +1,18 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/adder");
+    blockchain.register_contract("mxsc:output/adder.mxsc.json", adder::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn adder_rs() {
+    world().run("scenarios/adder.scen.json");
+}
+
+#[test]
+fn adder_upgrade_rs() {
+    world().run("scenarios/adder_upgrade.scen.json");
+}
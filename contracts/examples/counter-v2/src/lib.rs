@@ -0,0 +1,85 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod counter_v2_proxy;
+
+pub const ERR_UNDERFLOW: &str = "counter cannot go below zero";
+
+/// Layout version written by this code. Contracts deployed from `counter` have none, which reads as 0.
+pub const STORAGE_VERSION: u32 = 2;
+
+/// Second version of `counter`, deployable fresh or as an upgrade of it. The counter is a `BigUint`
+/// instead of a `u64`, and each caller has their own counter: increments and decrements move both
+/// the caller's counter and the total, so the total is always the sum of the user counters.
+#[multiversx_sc::contract]
+pub trait CounterV2 {
+    #[init]
+    fn init(&self) {
+        self.counter().set(BigUint::zero());
+        self.storage_version().set(STORAGE_VERSION);
+    }
+
+    /// Migrates the storage of a v1 contract. Upgrading a contract already on this layout changes nothing.
+    #[upgrade]
+    fn upgrade(&self) {
+        if self.storage_version().get() < STORAGE_VERSION {
+            self.migrate_from_v1();
+        }
+    }
+
+    #[endpoint]
+    fn increment(&self) {
+        self.increment_by(BigUint::from(1u32));
+    }
+
+    #[endpoint]
+    fn decrement(&self) {
+        self.decrement_by(BigUint::from(1u32));
+    }
+
+    #[endpoint(incrementBy)]
+    fn increment_by(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.user_counter(&caller).update(|c| *c += &amount);
+        self.counter().update(|c| *c += amount);
+    }
+
+    /// Only the caller's own increments can be taken back.
+    #[endpoint(decrementBy)]
+    fn decrement_by(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.user_counter(&caller).update(|c| {
+            require!(*c >= amount, ERR_UNDERFLOW);
+            *c -= &amount;
+        });
+        self.counter().update(|c| *c -= amount);
+    }
+
+    /// v1 kept a single `u64` and did not track callers. The total is read with the v1 type
+    /// and credited to the owner, who performs the upgrade.
+    fn migrate_from_v1(&self) {
+        // built from bytes: `BigUint::from(u64)` does not accept values above `i64::MAX`
+        let counter = BigUint::from_bytes_be(&self.v1_counter().get().to_be_bytes());
+        let owner = self.blockchain().get_owner_address();
+        self.user_counter(&owner).set(&counter);
+        self.counter().set(counter);
+        self.storage_version().set(STORAGE_VERSION);
+    }
+
+    #[view(get)]
+    #[storage_mapper("counter")]
+    fn counter(&self) -> SingleValueMapper<BigUint>;
+
+    /// The `counter` key as v1 wrote it.
+    #[storage_mapper("counter")]
+    fn v1_counter(&self) -> SingleValueMapper<u64>;
+
+    #[view(getUserCounter)]
+    #[storage_mapper("userCounter")]
+    fn user_counter(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getStorageVersion)]
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<u32>;
+}
@@ -0,0 +1,30 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/counter-v2");
+    blockchain.register_contract(
+        "mxsc:output/counter-v2.mxsc.json",
+        counter_v2::ContractBuilder,
+    );
+    blockchain.register_contract(
+        "mxsc:../counter/output/counter.mxsc.json",
+        counter::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn counter_v2_migration_rs() {
+    world().run("scenarios/counter-v2-migration.scen.json");
+}
+
+#[test]
+fn counter_v2_overflow_rs() {
+    world().run("scenarios/counter-v2-overflow.scen.json");
+}
+
+#[test]
+fn counter_v2_rs() {
+    world().run("scenarios/counter-v2.scen.json");
+}
@@ -0,0 +1,33 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            7
+// Async Callback (empty):               1
+// Total number of exported functions:  10
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    counter_v2
+    (
+        init => init
+        upgrade => upgrade
+        increment => increment
+        decrement => decrement
+        incrementBy => increment_by
+        decrementBy => decrement_by
+        get => counter
+        getUserCounter => user_counter
+        getStorageVersion => storage_version
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
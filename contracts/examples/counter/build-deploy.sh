@@ -9,9 +9,8 @@ CONTRACT_NAME="counter"
 SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
 PROJECT_ROOT="$(cd "$SCRIPT_DIR/../../.." && pwd)"
 
-PROXY="http://localhost:7950"
-CHAIN_ID="localnet"
-WALLET_PEM="$PROJECT_ROOT/wallets/wallet-owner.pem"
+PROXY="${PROXY:-http://localhost:7950}"
+WALLET_PEM="${WALLET_PEM:-$PROJECT_ROOT/wallets/wallet-owner.pem}"
 
 echo "================================"
 echo "Building $CONTRACT_NAME contract..."
@@ -57,15 +56,12 @@ fi
 
 echo "Deploying: $WASM_FILE"
 
-# Deploy contract
-mxpy contract deploy \
-    --bytecode="$WASM_FILE" \
+# Deploy through the Rust interactor; the deployment is recorded in contracts/deployments.toml
+cd "$SCRIPT_DIR/interactor"
+cargo run --release --bin counter-interact -- \
+    --gateway="$PROXY" \
     --pem="$WALLET_PEM" \
-    --proxy="$PROXY" \
-    --chain="$CHAIN_ID" \
-    --recall-nonce \
-    --gas-limit=5000000 \
-    --send
+    deploy
 
 if [ $? -eq 0 ]; then
     echo ""
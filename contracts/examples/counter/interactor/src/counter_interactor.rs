@@ -0,0 +1,224 @@
+mod counter_interactor_cli;
+mod counter_interactor_config;
+
+use clap::Parser;
+use counter::counter_proxy;
+pub use counter_interactor_config::Config;
+use deployment_registry::{DeploymentRecord, DeploymentRegistry, RegistryError};
+
+use multiversx_sc_snippets::{imports::*, multiversx_sc::contract_base::ContractAbiProvider};
+
+const CONTRACT_NAME: &str = "counter";
+const COUNTER_CODE_PATH: MxscPath = MxscPath::new("../output/counter.mxsc.json");
+
+pub async fn counter_cli() {
+    env_logger::init();
+
+    let cli = counter_interactor_cli::InteractCli::parse();
+    let mut config = Config::load_config();
+    if let Some(gateway) = cli.gateway {
+        config.gateway_uri = gateway;
+    }
+    if let Some(pem) = cli.pem {
+        config.owner_pem = Some(pem);
+    }
+
+    let mut interact = CounterInteract::new(config).await;
+    if let Err(err) = run_command(&mut interact, &cli.command).await {
+        println!("{err}");
+    }
+}
+
+async fn run_command(
+    interact: &mut CounterInteract,
+    command: &Option<counter_interactor_cli::InteractCliCommand>,
+) -> Result<(), RegistryError> {
+    match command {
+        Some(counter_interactor_cli::InteractCliCommand::Deploy) => {
+            interact.deploy().await;
+        }
+        Some(counter_interactor_cli::InteractCliCommand::Upgrade) => {
+            interact.upgrade().await?;
+        }
+        Some(counter_interactor_cli::InteractCliCommand::Increment(args)) => {
+            interact.increment(args.amount).await?;
+        }
+        Some(counter_interactor_cli::InteractCliCommand::Decrement(args)) => {
+            interact.decrement(args.amount).await?;
+        }
+        Some(counter_interactor_cli::InteractCliCommand::Get) => {
+            let counter = interact.get().await?;
+            println!("counter: {counter}");
+        }
+        None => {}
+    }
+
+    Ok(())
+}
+
+pub struct CounterInteract {
+    pub interactor: Interactor,
+    pub owner_address: Bech32Address,
+    pub network: String,
+    pub registry: DeploymentRegistry,
+}
+
+impl CounterInteract {
+    pub async fn new(config: Config) -> Self {
+        let mut interactor = Interactor::new(&config.gateway_uri)
+            .await
+            .use_chain_simulator(config.use_chain_simulator());
+        interactor.set_current_dir_from_workspace("examples/counter/interactor");
+
+        let owner_address = interactor.register_wallet(config.owner_wallet()).await;
+
+        // generate blocks until ESDTSystemSCActivationEpoch in order to be able to use the chain simulator
+        interactor.generate_blocks_until_epoch(1).await.unwrap();
+
+        let registry = DeploymentRegistry::load_default().unwrap_or_else(|err| {
+            println!("deployment registry not loaded: {err}");
+            DeploymentRegistry::default()
+        });
+
+        CounterInteract {
+            interactor,
+            owner_address: owner_address.into(),
+            network: config.network,
+            registry,
+        }
+    }
+
+    pub async fn deploy(&mut self) {
+        let (new_address, tx_hash) = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .gas(5_000_000)
+            .typed(counter_proxy::CounterProxy)
+            .init()
+            .code(COUNTER_CODE_PATH)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .returns(ReturnsNewBech32Address)
+            .returns(ReturnsTxHash)
+            .run()
+            .await;
+
+        println!("new address: {new_address}");
+        if let Err(err) = self
+            .record_deployment(new_address, tx_hash.as_bytes())
+            .await
+        {
+            println!("deployment not recorded in the registry: {err}");
+        }
+    }
+
+    pub async fn upgrade(&mut self) -> Result<(), RegistryError> {
+        let counter_address = self.counter_address()?;
+        let tx_hash = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&counter_address)
+            .gas(5_000_000)
+            .typed(counter_proxy::CounterProxy)
+            .upgrade()
+            .code(COUNTER_CODE_PATH)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .returns(ReturnsTxHash)
+            .run()
+            .await;
+
+        println!("upgraded {counter_address}");
+        if let Err(err) = self
+            .record_deployment(counter_address, tx_hash.as_bytes())
+            .await
+        {
+            println!("deployment not recorded in the registry: {err}");
+        }
+
+        Ok(())
+    }
+
+    pub async fn increment(&mut self, amount: u64) -> Result<(), RegistryError> {
+        let counter_address = self.counter_address()?;
+        let tx = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&counter_address)
+            .gas(5_000_000)
+            .typed(counter_proxy::CounterProxy);
+
+        if amount == 1 {
+            tx.increment().run().await;
+        } else {
+            tx.increment_by(amount).run().await;
+        }
+
+        println!("incremented by {amount}");
+
+        Ok(())
+    }
+
+    pub async fn decrement(&mut self, amount: u64) -> Result<(), RegistryError> {
+        let counter_address = self.counter_address()?;
+        let tx = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&counter_address)
+            .gas(5_000_000)
+            .typed(counter_proxy::CounterProxy);
+
+        if amount == 1 {
+            tx.decrement().run().await;
+        } else {
+            tx.decrement_by(amount).run().await;
+        }
+
+        println!("decremented by {amount}");
+
+        Ok(())
+    }
+
+    pub async fn get(&mut self) -> Result<u64, RegistryError> {
+        let counter_address = self.counter_address()?;
+        let counter = self
+            .interactor
+            .query()
+            .to(&counter_address)
+            .typed(counter_proxy::CounterProxy)
+            .counter()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+        Ok(counter)
+    }
+
+    /// Address of the counter contract recorded for the configured network.
+    pub fn counter_address(&self) -> Result<Bech32Address, RegistryError> {
+        self.registry.address(&self.network, CONTRACT_NAME).cloned()
+    }
+
+    async fn record_deployment(
+        &mut self,
+        address: Bech32Address,
+        tx_hash: &[u8],
+    ) -> Result<(), RegistryError> {
+        let abi_version = counter::AbiProvider::abi()
+            .build_info
+            .contract_crate
+            .version;
+        let record = DeploymentRecord::fetch(
+            &self.interactor,
+            address,
+            self.owner_address.clone(),
+            tx_hash,
+            abi_version,
+        )
+        .await?;
+
+        self.registry
+            .record_and_save(&self.network, CONTRACT_NAME, record)
+    }
+}
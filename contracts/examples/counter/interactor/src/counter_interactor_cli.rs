@@ -0,0 +1,40 @@
+use clap::{Args, Parser, Subcommand};
+
+/// Counter Interact CLI
+#[derive(Default, PartialEq, Eq, Debug, Parser)]
+#[command(version, about)]
+#[command(propagate_version = true)]
+pub struct InteractCli {
+    /// Overrides `gateway_uri` from config.toml
+    #[arg(long, global = true)]
+    pub gateway: Option<String>,
+
+    /// Overrides `owner_pem` from config.toml
+    #[arg(long, global = true)]
+    pub pem: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<InteractCliCommand>,
+}
+
+/// Counter Interact CLI Commands
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum InteractCliCommand {
+    #[command(name = "deploy", about = "Deploy contract")]
+    Deploy,
+    #[command(name = "upgrade", about = "Upgrade contract")]
+    Upgrade,
+    #[command(name = "increment", about = "Increment counter")]
+    Increment(AmountArgs),
+    #[command(name = "decrement", about = "Decrement counter")]
+    Decrement(AmountArgs),
+    #[command(name = "get", about = "Print counter")]
+    Get,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AmountArgs {
+    /// Step to apply; calls `incrementBy`/`decrementBy` when different from 1
+    #[arg(default_value_t = 1)]
+    pub amount: u64,
+}
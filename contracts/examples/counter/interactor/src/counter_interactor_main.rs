@@ -0,0 +1,6 @@
+use multiversx_sc_snippets::imports::*;
+
+#[tokio::main]
+async fn main() {
+    counter_interactor::counter_cli().await;
+}
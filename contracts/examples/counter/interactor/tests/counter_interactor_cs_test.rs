@@ -0,0 +1,20 @@
+use counter_interactor::{Config, CounterInteract};
+use multiversx_sc_snippets::imports::*;
+
+#[tokio::test]
+#[cfg_attr(not(feature = "chain-simulator-tests"), ignore)]
+async fn counter_simulator_deploy_increment_decrement_upgrade() {
+    let mut interact = CounterInteract::new(Config::chain_simulator_config()).await;
+
+    interact.deploy().await;
+    interact.increment(1).await.unwrap();
+    interact.increment(5).await.unwrap();
+    interact.decrement(2).await.unwrap();
+    assert_eq!(interact.get().await.unwrap(), 4);
+
+    interact.upgrade().await.unwrap();
+    assert_eq!(interact.get().await.unwrap(), 4);
+
+    interact.decrement(1).await.unwrap();
+    assert_eq!(interact.get().await.unwrap(), 3);
+}
@@ -0,0 +1,13 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait CounterEventsModule {
+    /// Emitted by every endpoint that changes the counter.
+    #[event("counterChanged")]
+    fn counter_changed_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] old: u64,
+        new: u64,
+    );
+}
@@ -2,8 +2,14 @@
 
 multiversx_sc::imports!();
 
+pub mod counter_events;
+pub mod counter_proxy;
+
+pub const ERR_UNDERFLOW: &str = "counter cannot go below zero";
+pub const ERR_OVERFLOW: &str = "counter overflow";
+
 #[multiversx_sc::contract]
-pub trait Counter {
+pub trait Counter: counter_events::CounterEventsModule {
     #[init]
     fn init(&self) {
         self.counter().set(0);
@@ -14,12 +20,33 @@ pub trait Counter {
 
     #[endpoint]
     fn increment(&self) {
-        self.counter().update(|c| *c += 1);
+        self.increment_by(1);
     }
 
     #[endpoint]
     fn decrement(&self) {
-        self.counter().update(|c| *c -= 1);
+        self.decrement_by(1);
+    }
+
+    #[endpoint(incrementBy)]
+    fn increment_by(&self, amount: u64) {
+        let old = self.counter().get();
+        let new = old
+            .checked_add(amount)
+            .unwrap_or_else(|| sc_panic!(ERR_OVERFLOW));
+        self.set_counter(old, new);
+    }
+
+    #[endpoint(decrementBy)]
+    fn decrement_by(&self, amount: u64) {
+        let old = self.counter().get();
+        require!(old >= amount, ERR_UNDERFLOW);
+        self.set_counter(old, old - amount);
+    }
+
+    fn set_counter(&self, old: u64, new: u64) {
+        self.counter().set(new);
+        self.counter_changed_event(&self.blockchain().get_caller(), old, new);
     }
 
     #[view(get)]
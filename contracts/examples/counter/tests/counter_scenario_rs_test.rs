@@ -0,0 +1,28 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/counter");
+    blockchain.register_contract("mxsc:output/counter.mxsc.json", counter::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn counter_rs() {
+    world().run("scenarios/counter.scen.json");
+}
+
+#[test]
+fn counter_upgrade_rs() {
+    world().run("scenarios/counter_upgrade.scen.json");
+}
+
+#[test]
+fn counter_underflow_rs() {
+    world().run("scenarios/counter_underflow.scen.json");
+}
+
+#[test]
+fn counter_by_amount_rs() {
+    world().run("scenarios/counter_by_amount.scen.json");
+}
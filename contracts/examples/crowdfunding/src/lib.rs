@@ -0,0 +1,121 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod crowdfunding_proxy;
+pub mod status;
+
+use status::Status;
+
+pub const ERR_ZERO_TARGET: &str = "target must be more than 0";
+pub const ERR_DEADLINE_IN_PAST: &str = "deadline must be in the future";
+pub const ERR_INVALID_TOKEN: &str = "invalid token";
+pub const ERR_FUNDING_CLOSED: &str = "cannot fund after deadline";
+pub const ERR_WRONG_TOKEN: &str = "wrong token";
+pub const ERR_ZERO_AMOUNT: &str = "amount cannot be zero";
+pub const ERR_FUNDING_OPEN: &str = "cannot claim before deadline";
+pub const ERR_ONLY_OWNER_CLAIMS: &str = "only owner can claim successful funding";
+pub const ERR_NOTHING_TO_CLAIM: &str = "nothing to claim";
+
+/// Collects donations in one token, EGLD or ESDT, until the deadline.
+/// If they reach the target, the owner claims them; otherwise each donor claims back their deposit.
+#[multiversx_sc::contract]
+pub trait Crowdfunding {
+    #[init]
+    fn init(&self, target: BigUint, deadline: u64, token_identifier: EgldOrEsdtTokenIdentifier) {
+        require!(target > 0, ERR_ZERO_TARGET);
+        require!(
+            deadline > self.blockchain().get_block_timestamp(),
+            ERR_DEADLINE_IN_PAST
+        );
+        require!(token_identifier.is_valid(), ERR_INVALID_TOKEN);
+
+        self.target().set(target);
+        self.deadline().set(deadline);
+        self.crowdfunding_token_identifier().set(token_identifier);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[payable("*")]
+    #[endpoint]
+    fn fund(&self) {
+        require!(self.status() == Status::FundingPeriod, ERR_FUNDING_CLOSED);
+        let (token_identifier, amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(
+            token_identifier == self.crowdfunding_token_identifier().get(),
+            ERR_WRONG_TOKEN
+        );
+        require!(amount > 0, ERR_ZERO_AMOUNT);
+
+        let caller = self.blockchain().get_caller();
+        self.deposit(&caller).update(|deposit| *deposit += &amount);
+        self.total_raised().update(|total| *total += &amount);
+    }
+
+    /// Sends the owner everything raised after a successful campaign,
+    /// or the caller their own deposit after a failed one.
+    #[endpoint]
+    fn claim(&self) {
+        let caller = self.blockchain().get_caller();
+        let amount = match self.status() {
+            Status::FundingPeriod => sc_panic!(ERR_FUNDING_OPEN),
+            Status::Successful => {
+                require!(
+                    caller == self.blockchain().get_owner_address(),
+                    ERR_ONLY_OWNER_CLAIMS
+                );
+                self.get_current_funds()
+            }
+            Status::Failed => self.deposit(&caller).take(),
+        };
+        require!(amount > 0, ERR_NOTHING_TO_CLAIM);
+
+        let token_identifier = self.crowdfunding_token_identifier().get();
+        self.tx()
+            .to(&caller)
+            .egld_or_single_esdt(&token_identifier, 0, &amount)
+            .transfer();
+    }
+
+    /// Decided by the total raised, so it does not change once funds are claimed.
+    #[view]
+    fn status(&self) -> Status {
+        if self.blockchain().get_block_timestamp() < self.deadline().get() {
+            Status::FundingPeriod
+        } else if self.total_raised().get() >= self.target().get() {
+            Status::Successful
+        } else {
+            Status::Failed
+        }
+    }
+
+    /// Balance held by the contract, in the crowdfunding token.
+    #[view(getCurrentFunds)]
+    fn get_current_funds(&self) -> BigUint {
+        let token_identifier = self.crowdfunding_token_identifier().get();
+        self.blockchain().get_sc_balance(&token_identifier, 0)
+    }
+
+    #[view(getTarget)]
+    #[storage_mapper("target")]
+    fn target(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getDeadline)]
+    #[storage_mapper("deadline")]
+    fn deadline(&self) -> SingleValueMapper<u64>;
+
+    #[view(getCrowdfundingTokenIdentifier)]
+    #[storage_mapper("tokenIdentifier")]
+    fn crowdfunding_token_identifier(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    /// Total donated by `donor`, until refunded.
+    #[view(getDeposit)]
+    #[storage_mapper("deposit")]
+    fn deposit(&self, donor: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalRaised)]
+    #[storage_mapper("totalRaised")]
+    fn total_raised(&self) -> SingleValueMapper<BigUint>;
+}
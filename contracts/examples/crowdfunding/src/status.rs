@@ -0,0 +1,12 @@
+multiversx_sc::derive_imports!();
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum Status {
+    /// Before the deadline; donations are accepted.
+    FundingPeriod,
+    /// The deadline passed with the target met; the owner can claim the funds.
+    Successful,
+    /// The deadline passed below the target; donors can claim refunds.
+    Failed,
+}
@@ -0,0 +1,36 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/crowdfunding");
+    blockchain.register_contract(
+        "mxsc:output/crowdfunding.mxsc.json",
+        crowdfunding::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn crowdfunding_esdt_rs() {
+    world().run("scenarios/crowdfunding-esdt.scen.json");
+}
+
+#[test]
+fn crowdfunding_failed_rs() {
+    world().run("scenarios/crowdfunding-failed.scen.json");
+}
+
+#[test]
+fn crowdfunding_fund_rs() {
+    world().run("scenarios/crowdfunding-fund.scen.json");
+}
+
+#[test]
+fn crowdfunding_setup_rs() {
+    world().run("scenarios/crowdfunding-setup.scen.json");
+}
+
+#[test]
+fn crowdfunding_successful_rs() {
+    world().run("scenarios/crowdfunding-successful.scen.json");
+}
@@ -0,0 +1,35 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            9
+// Async Callback (empty):               1
+// Total number of exported functions:  12
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    crowdfunding
+    (
+        init => init
+        upgrade => upgrade
+        fund => fund
+        claim => claim
+        status => status
+        getCurrentFunds => get_current_funds
+        getTarget => target
+        getDeadline => deadline
+        getCrowdfundingTokenIdentifier => crowdfunding_token_identifier
+        getDeposit => deposit
+        getTotalRaised => total_raised
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
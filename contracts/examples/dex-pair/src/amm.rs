@@ -0,0 +1,40 @@
+multiversx_sc::imports!();
+
+/// Fees are expressed in basis points of the input amount.
+pub const MAX_BASIS_POINTS: u64 = 10_000;
+
+/// Output for an exact input, after the fee, keeping `reserve_in * reserve_out` from decreasing.
+/// Rounds down, in favour of the pool.
+pub fn amount_out<M: ManagedTypeApi>(
+    amount_in: &BigUint<M>,
+    reserve_in: &BigUint<M>,
+    reserve_out: &BigUint<M>,
+    fee_bps: u64,
+) -> BigUint<M> {
+    let amount_in_with_fee = amount_in * (MAX_BASIS_POINTS - fee_bps);
+    let numerator = &amount_in_with_fee * reserve_out;
+    let denominator = reserve_in * MAX_BASIS_POINTS + amount_in_with_fee;
+    numerator / denominator
+}
+
+/// Input needed for an exact output, fee included. Rounds up, in favour of the pool.
+/// `amount_out` must be below `reserve_out`.
+pub fn amount_in<M: ManagedTypeApi>(
+    amount_out: &BigUint<M>,
+    reserve_in: &BigUint<M>,
+    reserve_out: &BigUint<M>,
+    fee_bps: u64,
+) -> BigUint<M> {
+    let numerator = reserve_in * amount_out * MAX_BASIS_POINTS;
+    let denominator = (reserve_out - amount_out) * (MAX_BASIS_POINTS - fee_bps);
+    numerator / denominator + 1u32
+}
+
+/// Amount of the other token worth `amount` at the current price, rounded down.
+pub fn quote<M: ManagedTypeApi>(
+    amount: &BigUint<M>,
+    reserve: &BigUint<M>,
+    other_reserve: &BigUint<M>,
+) -> BigUint<M> {
+    amount * other_reserve / reserve
+}
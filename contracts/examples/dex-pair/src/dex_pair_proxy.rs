@@ -0,0 +1,286 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct DexPairProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for DexPairProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = DexPairProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        DexPairProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct DexPairProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> DexPairProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        first_token_id: Arg0,
+        second_token_id: Arg1,
+        fee_bps: Arg2,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&first_token_id)
+            .argument(&second_token_id)
+            .argument(&fee_bps)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> DexPairProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> DexPairProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Issues the LP token and grants this contract the local mint/burn roles in one system SC call. 
+    /// The EGLD payment is the issue cost; it is returned to the caller if the issue fails. 
+    pub fn issue_lp_token<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        token_display_name: Arg0,
+        token_ticker: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("issueLpToken")
+            .argument(&token_display_name)
+            .argument(&token_ticker)
+            .original_result()
+    }
+
+    /// Deposits both pool tokens, in pool order, and mints LP tokens for them. 
+    /// After the first deposit only the amounts matching the current price are taken 
+    /// and the excess of one token is sent back; each amount used must reach its minimum. 
+    /// Returns the LP tokens minted and the amounts of each token added. 
+    pub fn add_liquidity<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        first_amount_min: Arg0,
+        second_amount_min: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValue3<EsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("addLiquidity")
+            .argument(&first_amount_min)
+            .argument(&second_amount_min)
+            .original_result()
+    }
+
+    /// Burns the LP tokens paid and sends back their share of both reserves. 
+    pub fn remove_liquidity<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        first_amount_min: Arg0,
+        second_amount_min: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValue2<EsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("removeLiquidity")
+            .argument(&first_amount_min)
+            .argument(&second_amount_min)
+            .original_result()
+    }
+
+    /// Swaps the whole payment for at least `amount_out_min` of `token_out`. 
+    pub fn swap_tokens_fixed_input<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_out: Arg0,
+        amount_out_min: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("swapTokensFixedInput")
+            .argument(&token_out)
+            .argument(&amount_out_min)
+            .original_result()
+    }
+
+    /// Swaps at most the payment for exactly `amount_out` of `token_out`. 
+    /// The unused part of the payment is sent back. 
+    /// Returns the tokens bought and the refund. 
+    pub fn swap_tokens_fixed_output<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_out: Arg0,
+        amount_out: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValue2<EsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("swapTokensFixedOutput")
+            .argument(&token_out)
+            .argument(&amount_out)
+            .original_result()
+    }
+
+    pub fn get_reserves(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReserves")
+            .original_result()
+    }
+
+    /// What `swapTokensFixedInput` would return for `amount_in` of `token_in` right now. 
+    pub fn get_amount_out<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_in: Arg0,
+        amount_in: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAmountOut")
+            .argument(&token_in)
+            .argument(&amount_in)
+            .original_result()
+    }
+
+    /// What `swapTokensFixedOutput` would take to buy `amount_out` of `token_out` right now. 
+    pub fn get_amount_in<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_out: Arg0,
+        amount_out: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAmountIn")
+            .argument(&token_out)
+            .argument(&amount_out)
+            .original_result()
+    }
+
+    /// Amount of the other pool token matching `amount` of `token_id` at the current price, 
+    /// as `addLiquidity` would take it. 
+    pub fn get_equivalent<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEquivalent")
+            .argument(&token_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn first_token_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFirstTokenId")
+            .original_result()
+    }
+
+    pub fn second_token_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSecondTokenId")
+            .original_result()
+    }
+
+    pub fn fee_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeeBps")
+            .original_result()
+    }
+
+    pub fn lp_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLpTokenId")
+            .original_result()
+    }
+
+    /// Includes the `MINIMUM_LIQUIDITY` locked in the contract. 
+    pub fn lp_token_supply(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLpTokenSupply")
+            .original_result()
+    }
+}
@@ -0,0 +1,389 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod amm;
+pub mod dex_pair_proxy;
+
+pub const ERR_INVALID_TOKEN: &str = "invalid token";
+pub const ERR_SAME_TOKENS: &str = "pool tokens must be different";
+pub const ERR_FEE_TOO_HIGH: &str = "fee cannot exceed 1000 basis points";
+pub const ERR_WRONG_TOKEN: &str = "wrong token";
+pub const ERR_BAD_LIQUIDITY_PAYMENTS: &str = "expected one payment of each pool token";
+pub const ERR_ZERO_AMOUNT: &str = "amount cannot be zero";
+pub const ERR_NO_LIQUIDITY: &str = "pool has no liquidity";
+pub const ERR_INSUFFICIENT_LIQUIDITY: &str = "not enough liquidity";
+pub const ERR_SLIPPAGE: &str = "slippage limit exceeded";
+
+/// 10%, to keep misconfigured pools from being unusable.
+pub const MAX_FEE_BPS: u64 = 1_000;
+
+/// LP tokens locked in the contract on the first deposit, so the pool can never be fully drained.
+pub const MINIMUM_LIQUIDITY: u64 = 1_000;
+
+/// Constant-product (x * y = k) pool for two ESDTs. Liquidity providers receive an LP token
+/// issued by the pool; every swap pays `feeBps` of its input to them.
+#[multiversx_sc::contract]
+pub trait DexPair {
+    #[init]
+    fn init(
+        &self,
+        first_token_id: TokenIdentifier,
+        second_token_id: TokenIdentifier,
+        fee_bps: u64,
+    ) {
+        require!(
+            first_token_id.is_valid_esdt_identifier() && second_token_id.is_valid_esdt_identifier(),
+            ERR_INVALID_TOKEN
+        );
+        require!(first_token_id != second_token_id, ERR_SAME_TOKENS);
+        require!(fee_bps <= MAX_FEE_BPS, ERR_FEE_TOO_HIGH);
+
+        self.first_token_id().set(first_token_id);
+        self.second_token_id().set(second_token_id);
+        self.fee_bps().set(fee_bps);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Issues the LP token and grants this contract the local mint/burn roles in one system SC call.
+    /// The EGLD payment is the issue cost; it is returned to the caller if the issue fails.
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueLpToken)]
+    fn issue_lp_token(&self, token_display_name: ManagedBuffer, token_ticker: ManagedBuffer) {
+        let issue_cost = self.call_value().egld().clone_value();
+        let caller = self.blockchain().get_caller();
+        self.lp_token().issue_and_set_all_roles(
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            18,
+            Some(self.callbacks().issue_callback(&caller)),
+        );
+    }
+
+    #[callback]
+    fn issue_callback(
+        &self,
+        caller: &ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.lp_token().set_token_id(token_id);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.lp_token().clear();
+                let returned = self.call_value().egld().clone_value();
+                self.tx().to(caller).egld(returned).transfer_if_not_empty();
+            }
+        }
+    }
+
+    /// Deposits both pool tokens, in pool order, and mints LP tokens for them.
+    /// After the first deposit only the amounts matching the current price are taken
+    /// and the excess of one token is sent back; each amount used must reach its minimum.
+    /// Returns the LP tokens minted and the amounts of each token added.
+    #[payable("*")]
+    #[endpoint(addLiquidity)]
+    fn add_liquidity(
+        &self,
+        first_amount_min: BigUint,
+        second_amount_min: BigUint,
+    ) -> MultiValue3<EsdtTokenPayment, EsdtTokenPayment, EsdtTokenPayment> {
+        let [first_payment, second_payment] = self.call_value().multi_esdt();
+        let first_token_id = self.first_token_id().get();
+        let second_token_id = self.second_token_id().get();
+        require!(
+            first_payment.token_identifier == first_token_id
+                && second_payment.token_identifier == second_token_id,
+            ERR_BAD_LIQUIDITY_PAYMENTS
+        );
+        require!(
+            first_payment.amount > 0 && second_payment.amount > 0,
+            ERR_ZERO_AMOUNT
+        );
+
+        let first_reserve = self.first_reserve().get();
+        let second_reserve = self.second_reserve().get();
+        let lp_supply = self.lp_token_supply().get();
+
+        let (first_amount, second_amount, liquidity) = if lp_supply == 0 {
+            let liquidity = (&first_payment.amount * &second_payment.amount).sqrt();
+            require!(liquidity > MINIMUM_LIQUIDITY, ERR_INSUFFICIENT_LIQUIDITY);
+            self.lp_token().mint(BigUint::from(MINIMUM_LIQUIDITY));
+            self.lp_token_supply().set(BigUint::from(MINIMUM_LIQUIDITY));
+
+            (
+                first_payment.amount.clone(),
+                second_payment.amount.clone(),
+                liquidity - MINIMUM_LIQUIDITY,
+            )
+        } else {
+            let second_optimal = amm::quote(&first_payment.amount, &first_reserve, &second_reserve);
+            let (first_amount, second_amount) = if second_optimal <= second_payment.amount {
+                (first_payment.amount.clone(), second_optimal)
+            } else {
+                let first_optimal =
+                    amm::quote(&second_payment.amount, &second_reserve, &first_reserve);
+                (first_optimal, second_payment.amount.clone())
+            };
+
+            let first_liquidity = &first_amount * &lp_supply / &first_reserve;
+            let second_liquidity = &second_amount * &lp_supply / &second_reserve;
+            let liquidity = core::cmp::min(first_liquidity, second_liquidity);
+            (first_amount, second_amount, liquidity)
+        };
+        require!(
+            first_amount >= first_amount_min && second_amount >= second_amount_min,
+            ERR_SLIPPAGE
+        );
+        require!(liquidity > 0, ERR_INSUFFICIENT_LIQUIDITY);
+
+        self.first_reserve().set(first_reserve + &first_amount);
+        self.second_reserve().set(second_reserve + &second_amount);
+        self.lp_token_supply()
+            .update(|supply| *supply += &liquidity);
+
+        let caller = self.blockchain().get_caller();
+        let lp_payment = self.lp_token().mint_and_send(&caller, liquidity);
+        self.send_if_not_empty(
+            &caller,
+            &first_token_id,
+            &(&first_payment.amount - &first_amount),
+        );
+        self.send_if_not_empty(
+            &caller,
+            &second_token_id,
+            &(&second_payment.amount - &second_amount),
+        );
+
+        (
+            lp_payment,
+            EsdtTokenPayment::new(first_token_id, 0, first_amount),
+            EsdtTokenPayment::new(second_token_id, 0, second_amount),
+        )
+            .into()
+    }
+
+    /// Burns the LP tokens paid and sends back their share of both reserves.
+    #[payable("*")]
+    #[endpoint(removeLiquidity)]
+    fn remove_liquidity(
+        &self,
+        first_amount_min: BigUint,
+        second_amount_min: BigUint,
+    ) -> MultiValue2<EsdtTokenPayment, EsdtTokenPayment> {
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.lp_token().get_token_id(),
+            ERR_WRONG_TOKEN
+        );
+        require!(payment.amount > 0, ERR_ZERO_AMOUNT);
+
+        let first_reserve = self.first_reserve().get();
+        let second_reserve = self.second_reserve().get();
+        let lp_supply = self.lp_token_supply().get();
+        let first_amount = &payment.amount * &first_reserve / &lp_supply;
+        let second_amount = &payment.amount * &second_reserve / &lp_supply;
+        require!(
+            first_amount >= first_amount_min && second_amount >= second_amount_min,
+            ERR_SLIPPAGE
+        );
+        require!(
+            first_amount > 0 && second_amount > 0,
+            ERR_INSUFFICIENT_LIQUIDITY
+        );
+
+        self.first_reserve().set(first_reserve - &first_amount);
+        self.second_reserve().set(second_reserve - &second_amount);
+        self.lp_token_supply().set(lp_supply - &payment.amount);
+        self.lp_token().burn(&payment.amount);
+
+        let caller = self.blockchain().get_caller();
+        let first_payment = EsdtTokenPayment::new(self.first_token_id().get(), 0, first_amount);
+        let second_payment = EsdtTokenPayment::new(self.second_token_id().get(), 0, second_amount);
+        self.tx().to(&caller).payment(&first_payment).transfer();
+        self.tx().to(&caller).payment(&second_payment).transfer();
+
+        (first_payment, second_payment).into()
+    }
+
+    /// Swaps the whole payment for at least `amount_out_min` of `token_out`.
+    #[payable("*")]
+    #[endpoint(swapTokensFixedInput)]
+    fn swap_tokens_fixed_input(
+        &self,
+        token_out: TokenIdentifier,
+        amount_out_min: BigUint,
+    ) -> EsdtTokenPayment {
+        let payment = self.call_value().single_esdt();
+        require!(payment.amount > 0, ERR_ZERO_AMOUNT);
+        let (reserve_in, reserve_out) = self.swap_reserves(&payment.token_identifier, &token_out);
+
+        let amount_out = amm::amount_out(
+            &payment.amount,
+            &reserve_in.get(),
+            &reserve_out.get(),
+            self.fee_bps().get(),
+        );
+        require!(amount_out >= amount_out_min, ERR_SLIPPAGE);
+        require!(amount_out > 0, ERR_INSUFFICIENT_LIQUIDITY);
+
+        reserve_in.update(|reserve| *reserve += &payment.amount);
+        reserve_out.update(|reserve| *reserve -= &amount_out);
+
+        let payment_out = EsdtTokenPayment::new(token_out, 0, amount_out);
+        self.tx()
+            .to(&self.blockchain().get_caller())
+            .payment(&payment_out)
+            .transfer();
+        payment_out
+    }
+
+    /// Swaps at most the payment for exactly `amount_out` of `token_out`.
+    /// The unused part of the payment is sent back.
+    /// Returns the tokens bought and the refund.
+    #[payable("*")]
+    #[endpoint(swapTokensFixedOutput)]
+    fn swap_tokens_fixed_output(
+        &self,
+        token_out: TokenIdentifier,
+        amount_out: BigUint,
+    ) -> MultiValue2<EsdtTokenPayment, EsdtTokenPayment> {
+        let payment = self.call_value().single_esdt();
+        require!(amount_out > 0, ERR_ZERO_AMOUNT);
+        let (reserve_in, reserve_out) = self.swap_reserves(&payment.token_identifier, &token_out);
+        require!(amount_out < reserve_out.get(), ERR_INSUFFICIENT_LIQUIDITY);
+
+        let amount_in = amm::amount_in(
+            &amount_out,
+            &reserve_in.get(),
+            &reserve_out.get(),
+            self.fee_bps().get(),
+        );
+        require!(amount_in <= payment.amount, ERR_SLIPPAGE);
+
+        reserve_in.update(|reserve| *reserve += &amount_in);
+        reserve_out.update(|reserve| *reserve -= &amount_out);
+
+        let caller = self.blockchain().get_caller();
+        let payment_out = EsdtTokenPayment::new(token_out, 0, amount_out);
+        let refund = EsdtTokenPayment::new(
+            payment.token_identifier.clone(),
+            0,
+            &payment.amount - &amount_in,
+        );
+        self.tx().to(&caller).payment(&payment_out).transfer();
+        self.send_if_not_empty(&caller, &refund.token_identifier, &refund.amount);
+
+        (payment_out, refund).into()
+    }
+
+    /// Reserve mappers for a swap from `token_in` to `token_out`, in that order.
+    fn swap_reserves(
+        &self,
+        token_in: &TokenIdentifier,
+        token_out: &TokenIdentifier,
+    ) -> (SingleValueMapper<BigUint>, SingleValueMapper<BigUint>) {
+        let first_token_id = self.first_token_id().get();
+        let second_token_id = self.second_token_id().get();
+        let reserves = if *token_in == first_token_id && *token_out == second_token_id {
+            (self.first_reserve(), self.second_reserve())
+        } else if *token_in == second_token_id && *token_out == first_token_id {
+            (self.second_reserve(), self.first_reserve())
+        } else {
+            sc_panic!(ERR_WRONG_TOKEN);
+        };
+        require!(
+            reserves.0.get() > 0 && reserves.1.get() > 0,
+            ERR_NO_LIQUIDITY
+        );
+        reserves
+    }
+
+    fn send_if_not_empty(&self, to: &ManagedAddress, token_id: &TokenIdentifier, amount: &BigUint) {
+        if *amount > 0 {
+            self.tx().to(to).single_esdt(token_id, 0, amount).transfer();
+        }
+    }
+
+    #[view(getReserves)]
+    fn get_reserves(&self) -> MultiValue2<BigUint, BigUint> {
+        (self.first_reserve().get(), self.second_reserve().get()).into()
+    }
+
+    /// What `swapTokensFixedInput` would return for `amount_in` of `token_in` right now.
+    #[view(getAmountOut)]
+    fn get_amount_out(&self, token_in: TokenIdentifier, amount_in: BigUint) -> BigUint {
+        let token_out = self.other_token(&token_in);
+        let (reserve_in, reserve_out) = self.swap_reserves(&token_in, &token_out);
+        amm::amount_out(
+            &amount_in,
+            &reserve_in.get(),
+            &reserve_out.get(),
+            self.fee_bps().get(),
+        )
+    }
+
+    /// What `swapTokensFixedOutput` would take to buy `amount_out` of `token_out` right now.
+    #[view(getAmountIn)]
+    fn get_amount_in(&self, token_out: TokenIdentifier, amount_out: BigUint) -> BigUint {
+        let token_in = self.other_token(&token_out);
+        let (reserve_in, reserve_out) = self.swap_reserves(&token_in, &token_out);
+        require!(amount_out < reserve_out.get(), ERR_INSUFFICIENT_LIQUIDITY);
+        amm::amount_in(
+            &amount_out,
+            &reserve_in.get(),
+            &reserve_out.get(),
+            self.fee_bps().get(),
+        )
+    }
+
+    /// Amount of the other pool token matching `amount` of `token_id` at the current price,
+    /// as `addLiquidity` would take it.
+    #[view(getEquivalent)]
+    fn get_equivalent(&self, token_id: TokenIdentifier, amount: BigUint) -> BigUint {
+        let other_token_id = self.other_token(&token_id);
+        let (reserve, other_reserve) = self.swap_reserves(&token_id, &other_token_id);
+        amm::quote(&amount, &reserve.get(), &other_reserve.get())
+    }
+
+    fn other_token(&self, token_id: &TokenIdentifier) -> TokenIdentifier {
+        let first_token_id = self.first_token_id().get();
+        if *token_id == first_token_id {
+            self.second_token_id().get()
+        } else {
+            first_token_id
+        }
+    }
+
+    #[view(getFirstTokenId)]
+    #[storage_mapper("firstTokenId")]
+    fn first_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getSecondTokenId)]
+    #[storage_mapper("secondTokenId")]
+    fn second_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getFeeBps)]
+    #[storage_mapper("feeBps")]
+    fn fee_bps(&self) -> SingleValueMapper<u64>;
+
+    #[view(getLpTokenId)]
+    #[storage_mapper("lpToken")]
+    fn lp_token(&self) -> FungibleTokenMapper;
+
+    /// Includes the `MINIMUM_LIQUIDITY` locked in the contract.
+    #[view(getLpTokenSupply)]
+    #[storage_mapper("lpTokenSupply")]
+    fn lp_token_supply(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("firstReserve")]
+    fn first_reserve(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("secondReserve")]
+    fn second_reserve(&self) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,23 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/dex-pair");
+    blockchain.register_contract("mxsc:output/dex-pair.mxsc.json", dex_pair::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn dex_pair_liquidity_rs() {
+    world().run("scenarios/dex-pair-liquidity.scen.json");
+}
+
+#[test]
+fn dex_pair_setup_rs() {
+    world().run("scenarios/dex-pair-setup.scen.json");
+}
+
+#[test]
+fn dex_pair_swap_rs() {
+    world().run("scenarios/dex-pair-swap.scen.json");
+}
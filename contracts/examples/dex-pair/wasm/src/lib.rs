@@ -0,0 +1,40 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           14
+// Async Callback:                       1
+// Total number of exported functions:  17
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    dex_pair
+    (
+        init => init
+        upgrade => upgrade
+        issueLpToken => issue_lp_token
+        addLiquidity => add_liquidity
+        removeLiquidity => remove_liquidity
+        swapTokensFixedInput => swap_tokens_fixed_input
+        swapTokensFixedOutput => swap_tokens_fixed_output
+        getReserves => get_reserves
+        getAmountOut => get_amount_out
+        getAmountIn => get_amount_in
+        getEquivalent => get_equivalent
+        getFirstTokenId => first_token_id
+        getSecondTokenId => second_token_id
+        getFeeBps => fee_bps
+        getLpTokenId => lp_token
+        getLpTokenSupply => lp_token_supply
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback! { dex_pair }
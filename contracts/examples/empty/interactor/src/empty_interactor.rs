@@ -0,0 +1,155 @@
+mod empty_interactor_cli;
+mod empty_interactor_config;
+
+use clap::Parser;
+use deployment_registry::{DeploymentRecord, DeploymentRegistry, RegistryError};
+use empty::empty_proxy;
+pub use empty_interactor_config::Config;
+
+use multiversx_sc_snippets::{imports::*, multiversx_sc::contract_base::ContractAbiProvider};
+
+const CONTRACT_NAME: &str = "empty";
+const EMPTY_CODE_PATH: MxscPath = MxscPath::new("../output/empty.mxsc.json");
+
+pub async fn empty_cli() {
+    env_logger::init();
+
+    let cli = empty_interactor_cli::InteractCli::parse();
+    let mut config = Config::load_config();
+    if let Some(gateway) = cli.gateway {
+        config.gateway_uri = gateway;
+    }
+    if let Some(pem) = cli.pem {
+        config.owner_pem = Some(pem);
+    }
+
+    let mut interact = EmptyInteract::new(config).await;
+    if let Err(err) = run_command(&mut interact, &cli.command).await {
+        println!("{err}");
+    }
+}
+
+async fn run_command(
+    interact: &mut EmptyInteract,
+    command: &Option<empty_interactor_cli::InteractCliCommand>,
+) -> Result<(), RegistryError> {
+    match command {
+        Some(empty_interactor_cli::InteractCliCommand::Deploy) => {
+            interact.deploy().await;
+        }
+        Some(empty_interactor_cli::InteractCliCommand::Upgrade) => {
+            interact.upgrade().await?;
+        }
+        None => {}
+    }
+
+    Ok(())
+}
+
+pub struct EmptyInteract {
+    pub interactor: Interactor,
+    pub owner_address: Bech32Address,
+    pub network: String,
+    pub registry: DeploymentRegistry,
+}
+
+impl EmptyInteract {
+    pub async fn new(config: Config) -> Self {
+        let mut interactor = Interactor::new(&config.gateway_uri)
+            .await
+            .use_chain_simulator(config.use_chain_simulator());
+        interactor.set_current_dir_from_workspace("examples/empty/interactor");
+
+        let owner_address = interactor.register_wallet(config.owner_wallet()).await;
+
+        // generate blocks until ESDTSystemSCActivationEpoch in order to be able to use the chain simulator
+        interactor.generate_blocks_until_epoch(1).await.unwrap();
+
+        let registry = DeploymentRegistry::load_default().unwrap_or_else(|err| {
+            println!("deployment registry not loaded: {err}");
+            DeploymentRegistry::default()
+        });
+
+        EmptyInteract {
+            interactor,
+            owner_address: owner_address.into(),
+            network: config.network,
+            registry,
+        }
+    }
+
+    pub async fn deploy(&mut self) {
+        let (new_address, tx_hash) = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .gas(5_000_000)
+            .typed(empty_proxy::EmptyProxy)
+            .init()
+            .code(EMPTY_CODE_PATH)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .returns(ReturnsNewBech32Address)
+            .returns(ReturnsTxHash)
+            .run()
+            .await;
+
+        println!("new address: {new_address}");
+        if let Err(err) = self
+            .record_deployment(new_address, tx_hash.as_bytes())
+            .await
+        {
+            println!("deployment not recorded in the registry: {err}");
+        }
+    }
+
+    pub async fn upgrade(&mut self) -> Result<(), RegistryError> {
+        let empty_address = self.empty_address()?;
+        let tx_hash = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&empty_address)
+            .gas(5_000_000)
+            .typed(empty_proxy::EmptyProxy)
+            .upgrade()
+            .code(EMPTY_CODE_PATH)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .returns(ReturnsTxHash)
+            .run()
+            .await;
+
+        println!("upgraded {empty_address}");
+        if let Err(err) = self
+            .record_deployment(empty_address, tx_hash.as_bytes())
+            .await
+        {
+            println!("deployment not recorded in the registry: {err}");
+        }
+
+        Ok(())
+    }
+
+    /// Address of the empty contract recorded for the configured network.
+    pub fn empty_address(&self) -> Result<Bech32Address, RegistryError> {
+        self.registry.address(&self.network, CONTRACT_NAME).cloned()
+    }
+
+    async fn record_deployment(
+        &mut self,
+        address: Bech32Address,
+        tx_hash: &[u8],
+    ) -> Result<(), RegistryError> {
+        let abi_version = empty::AbiProvider::abi().build_info.contract_crate.version;
+        let record = DeploymentRecord::fetch(
+            &self.interactor,
+            address,
+            self.owner_address.clone(),
+            tx_hash,
+            abi_version,
+        )
+        .await?;
+
+        self.registry
+            .record_and_save(&self.network, CONTRACT_NAME, record)
+    }
+}
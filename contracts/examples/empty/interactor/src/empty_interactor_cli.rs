@@ -0,0 +1,27 @@
+use clap::{Parser, Subcommand};
+
+/// Empty Interact CLI
+#[derive(Default, PartialEq, Eq, Debug, Parser)]
+#[command(version, about)]
+#[command(propagate_version = true)]
+pub struct InteractCli {
+    /// Overrides `gateway_uri` from config.toml
+    #[arg(long, global = true)]
+    pub gateway: Option<String>,
+
+    /// Overrides `owner_pem` from config.toml
+    #[arg(long, global = true)]
+    pub pem: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<InteractCliCommand>,
+}
+
+/// Empty Interact CLI Commands
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum InteractCliCommand {
+    #[command(name = "deploy", about = "Deploy contract")]
+    Deploy,
+    #[command(name = "upgrade", about = "Upgrade contract")]
+    Upgrade,
+}
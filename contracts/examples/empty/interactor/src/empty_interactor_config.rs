@@ -0,0 +1,58 @@
+use multiversx_sc_snippets::imports::*;
+use serde::Deserialize;
+use std::io::Read;
+
+/// Config file
+const CONFIG_FILE: &str = "config.toml";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChainType {
+    Real,
+    Simulator,
+}
+
+/// Empty Interact configuration
+#[derive(Debug, Deserialize)]
+pub struct Config {
+    pub gateway_uri: String,
+    pub chain_type: ChainType,
+    pub network: String,
+    pub owner_pem: Option<String>,
+}
+
+impl Config {
+    /// Deserializes config from file
+    pub fn load_config() -> Self {
+        let mut file = std::fs::File::open(CONFIG_FILE).unwrap();
+        let mut content = String::new();
+        file.read_to_string(&mut content).unwrap();
+        toml::from_str(&content).unwrap()
+    }
+
+    /// Config for a chain simulator running on its default port, funding the test wallets.
+    pub fn chain_simulator_config() -> Self {
+        Config {
+            gateway_uri: "http://localhost:8085".to_owned(),
+            chain_type: ChainType::Simulator,
+            network: "chain-simulator".to_owned(),
+            owner_pem: None,
+        }
+    }
+
+    pub fn use_chain_simulator(&self) -> bool {
+        match self.chain_type {
+            ChainType::Real => false,
+            ChainType::Simulator => true,
+        }
+    }
+
+    /// The configured PEM wallet, or Alice from the test wallets when none is set.
+    pub fn owner_wallet(&self) -> Wallet {
+        match &self.owner_pem {
+            Some(path) => Wallet::from_pem_file(path)
+                .unwrap_or_else(|err| panic!("cannot load owner wallet from {path}: {err}")),
+            None => test_wallets::alice(),
+        }
+    }
+}
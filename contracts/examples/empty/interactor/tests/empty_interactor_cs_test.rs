@@ -0,0 +1,11 @@
+use empty_interactor::{Config, EmptyInteract};
+use multiversx_sc_snippets::imports::*;
+
+#[tokio::test]
+#[cfg_attr(not(feature = "chain-simulator-tests"), ignore)]
+async fn empty_simulator_deploy_upgrade() {
+    let mut interact = EmptyInteract::new(Config::chain_simulator_config()).await;
+
+    interact.deploy().await;
+    interact.upgrade().await.unwrap();
+}
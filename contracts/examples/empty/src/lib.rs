@@ -2,6 +2,8 @@
 
 multiversx_sc::imports!();
 
+pub mod empty_proxy;
+
 #[multiversx_sc::contract]
 pub trait Empty {
     #[init]
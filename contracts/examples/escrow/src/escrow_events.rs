@@ -0,0 +1,51 @@
+multiversx_sc::imports!();
+
+use crate::offer::Offer;
+
+/// One event per offer transition, with the offer id and the affected party as topics.
+#[multiversx_sc::module]
+pub trait EscrowEventsModule {
+    #[event("offerCreated")]
+    fn offer_created_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] buyer: &ManagedAddress,
+        #[indexed] seller: &ManagedAddress,
+        offer: &Offer<Self::Api>,
+    );
+
+    #[event("offerReleased")]
+    fn offer_released_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] seller: &ManagedAddress,
+        payment: &EgldOrEsdtTokenPayment,
+    );
+
+    #[event("offerRefunded")]
+    fn offer_refunded_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] buyer: &ManagedAddress,
+        payment: &EgldOrEsdtTokenPayment,
+    );
+
+    #[event("offerCancelled")]
+    fn offer_cancelled_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] buyer: &ManagedAddress,
+        payment: &EgldOrEsdtTokenPayment,
+    );
+
+    #[event("offerDisputed")]
+    fn offer_disputed_event(&self, #[indexed] offer_id: u64, #[indexed] caller: &ManagedAddress);
+
+    #[event("disputeResolved")]
+    fn dispute_resolved_event(
+        &self,
+        #[indexed] offer_id: u64,
+        #[indexed] receiver: &ManagedAddress,
+        payment: &EgldOrEsdtTokenPayment,
+    );
+}
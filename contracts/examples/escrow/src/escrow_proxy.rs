@@ -0,0 +1,229 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct EscrowProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for EscrowProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = EscrowProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        EscrowProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct EscrowProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> EscrowProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init(
+        self,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> EscrowProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> EscrowProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Locks the payment in a new offer from the caller to `seller`. Returns the offer id. 
+    pub fn create<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        seller: Arg0,
+        refund_deadline: Arg1,
+        opt_arbiter: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("create")
+            .argument(&seller)
+            .argument(&refund_deadline)
+            .argument(&opt_arbiter)
+            .original_result()
+    }
+
+    /// The buyer approves the deal and the payment goes to the seller, even during a dispute. 
+    pub fn release<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("release")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    /// The buyer takes the payment back once the refund deadline has passed, 
+    /// unless the offer is under dispute. 
+    pub fn refund<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refund")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    /// The seller backs out and the payment goes back to the buyer, even during a dispute. 
+    pub fn cancel<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancel")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    /// Either party hands the offer over to its arbiter. 
+    pub fn dispute<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("dispute")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    /// The arbiter sends the disputed payment to the seller or back to the buyer. 
+    pub fn resolve_dispute<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        offer_id: Arg0,
+        release_to_seller: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resolveDispute")
+            .argument(&offer_id)
+            .argument(&release_to_seller)
+            .original_result()
+    }
+
+    pub fn get_offer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Offer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOffer")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    /// Every offer not yet settled, by id. 
+    pub fn get_open_offers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, Offer<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOpenOffers")
+            .original_result()
+    }
+
+    pub fn last_offer_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastOfferId")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Offer<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub buyer: ManagedAddress<Api>,
+    pub seller: ManagedAddress<Api>,
+    pub arbiter: Option<ManagedAddress<Api>>,
+    pub payment: EgldOrEsdtTokenPayment<Api>,
+    pub refund_deadline: u64,
+    pub status: OfferStatus,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum OfferStatus {
+    Active,
+    Disputed,
+}
@@ -0,0 +1,195 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod escrow_events;
+pub mod escrow_proxy;
+pub mod offer;
+
+use offer::{Offer, OfferStatus};
+
+pub const ERR_OFFER_NOT_FOUND: &str = "offer does not exist";
+pub const ERR_ZERO_PAYMENT: &str = "payment cannot be zero";
+pub const ERR_INVALID_PARTIES: &str = "buyer, seller and arbiter must be different";
+pub const ERR_DEADLINE_IN_PAST: &str = "refund deadline must be in the future";
+pub const ERR_ONLY_BUYER: &str = "only the buyer can do this";
+pub const ERR_ONLY_SELLER: &str = "only the seller can do this";
+pub const ERR_ONLY_PARTIES: &str = "only the buyer or the seller can open a dispute";
+pub const ERR_ONLY_ARBITER: &str = "only the arbiter can resolve a dispute";
+pub const ERR_NO_ARBITER: &str = "offer has no arbiter";
+pub const ERR_DISPUTED: &str = "offer is disputed";
+pub const ERR_NOT_DISPUTED: &str = "offer is not disputed";
+pub const ERR_DEADLINE_NOT_REACHED: &str = "refund deadline not reached";
+
+/// Holds a buyer's EGLD or ESDT payment until the buyer releases it to the seller.
+/// The buyer takes it back after the refund deadline, the seller can cancel at any time,
+/// and an optional arbiter settles disputes either way.
+#[multiversx_sc::contract]
+pub trait Escrow: escrow_events::EscrowEventsModule {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Locks the payment in a new offer from the caller to `seller`. Returns the offer id.
+    #[payable("*")]
+    #[endpoint]
+    fn create(
+        &self,
+        seller: ManagedAddress,
+        refund_deadline: u64,
+        opt_arbiter: OptionalValue<ManagedAddress>,
+    ) -> u64 {
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.amount > 0, ERR_ZERO_PAYMENT);
+
+        let buyer = self.blockchain().get_caller();
+        let arbiter = opt_arbiter.into_option();
+        require!(buyer != seller, ERR_INVALID_PARTIES);
+        if let Some(arbiter) = &arbiter {
+            require!(*arbiter != buyer && *arbiter != seller, ERR_INVALID_PARTIES);
+        }
+        require!(
+            refund_deadline > self.blockchain().get_block_timestamp(),
+            ERR_DEADLINE_IN_PAST
+        );
+
+        let offer_id = self.last_offer_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let offer = Offer {
+            buyer,
+            seller,
+            arbiter,
+            payment,
+            refund_deadline,
+            status: OfferStatus::Active,
+        };
+        self.offer_created_event(offer_id, &offer.buyer, &offer.seller, &offer);
+        self.offers().insert(offer_id, offer);
+
+        offer_id
+    }
+
+    /// The buyer approves the deal and the payment goes to the seller, even during a dispute.
+    #[endpoint]
+    fn release(&self, offer_id: u64) {
+        let offer = self.require_offer(offer_id);
+        require!(
+            self.blockchain().get_caller() == offer.buyer,
+            ERR_ONLY_BUYER
+        );
+
+        self.offer_released_event(offer_id, &offer.seller, &offer.payment);
+        self.settle(offer_id, &offer.seller, &offer.payment);
+    }
+
+    /// The buyer takes the payment back once the refund deadline has passed,
+    /// unless the offer is under dispute.
+    #[endpoint]
+    fn refund(&self, offer_id: u64) {
+        let offer = self.require_offer(offer_id);
+        require!(
+            self.blockchain().get_caller() == offer.buyer,
+            ERR_ONLY_BUYER
+        );
+        require!(offer.status == OfferStatus::Active, ERR_DISPUTED);
+        require!(
+            self.blockchain().get_block_timestamp() >= offer.refund_deadline,
+            ERR_DEADLINE_NOT_REACHED
+        );
+
+        self.offer_refunded_event(offer_id, &offer.buyer, &offer.payment);
+        self.settle(offer_id, &offer.buyer, &offer.payment);
+    }
+
+    /// The seller backs out and the payment goes back to the buyer, even during a dispute.
+    #[endpoint]
+    fn cancel(&self, offer_id: u64) {
+        let offer = self.require_offer(offer_id);
+        require!(
+            self.blockchain().get_caller() == offer.seller,
+            ERR_ONLY_SELLER
+        );
+
+        self.offer_cancelled_event(offer_id, &offer.buyer, &offer.payment);
+        self.settle(offer_id, &offer.buyer, &offer.payment);
+    }
+
+    /// Either party hands the offer over to its arbiter.
+    #[endpoint]
+    fn dispute(&self, offer_id: u64) {
+        let mut offer = self.require_offer(offer_id);
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == offer.buyer || caller == offer.seller,
+            ERR_ONLY_PARTIES
+        );
+        require!(offer.arbiter.is_some(), ERR_NO_ARBITER);
+        require!(offer.status == OfferStatus::Active, ERR_DISPUTED);
+
+        offer.status = OfferStatus::Disputed;
+        self.offers().insert(offer_id, offer);
+        self.offer_disputed_event(offer_id, &caller);
+    }
+
+    /// The arbiter sends the disputed payment to the seller or back to the buyer.
+    #[endpoint(resolveDispute)]
+    fn resolve_dispute(&self, offer_id: u64, release_to_seller: bool) {
+        let offer = self.require_offer(offer_id);
+        require!(
+            offer.arbiter == Some(self.blockchain().get_caller()),
+            ERR_ONLY_ARBITER
+        );
+        require!(offer.status == OfferStatus::Disputed, ERR_NOT_DISPUTED);
+
+        let receiver = if release_to_seller {
+            &offer.seller
+        } else {
+            &offer.buyer
+        };
+        self.dispute_resolved_event(offer_id, receiver, &offer.payment);
+        self.settle(offer_id, receiver, &offer.payment);
+    }
+
+    fn require_offer(&self, offer_id: u64) -> Offer<Self::Api> {
+        let opt_offer = self.offers().get(&offer_id);
+        require!(opt_offer.is_some(), ERR_OFFER_NOT_FOUND);
+        opt_offer.unwrap()
+    }
+
+    fn settle(&self, offer_id: u64, receiver: &ManagedAddress, payment: &EgldOrEsdtTokenPayment) {
+        self.offers().remove(&offer_id);
+        self.tx()
+            .to(receiver)
+            .egld_or_single_esdt(
+                &payment.token_identifier,
+                payment.token_nonce,
+                &payment.amount,
+            )
+            .transfer();
+    }
+
+    #[view(getOffer)]
+    fn get_offer(&self, offer_id: u64) -> Offer<Self::Api> {
+        self.require_offer(offer_id)
+    }
+
+    /// Every offer not yet settled, by id.
+    #[view(getOpenOffers)]
+    fn get_open_offers(&self) -> MultiValueEncoded<MultiValue2<u64, Offer<Self::Api>>> {
+        self.offers()
+            .iter()
+            .map(|(offer_id, offer)| (offer_id, offer).into())
+            .collect()
+    }
+
+    #[view(getLastOfferId)]
+    #[storage_mapper("lastOfferId")]
+    fn last_offer_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("offers")]
+    fn offers(&self) -> MapMapper<u64, Offer<Self::Api>>;
+}
@@ -0,0 +1,23 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum OfferStatus {
+    Active,
+    /// Frozen until the arbiter resolves it; the timeout refund no longer applies.
+    Disputed,
+}
+
+/// Payment held between a buyer and a seller. Removed from storage once settled.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Offer<M: ManagedTypeApi> {
+    pub buyer: ManagedAddress<M>,
+    pub seller: ManagedAddress<M>,
+    pub arbiter: Option<ManagedAddress<M>>,
+    pub payment: EgldOrEsdtTokenPayment<M>,
+    /// Block timestamp from which the buyer can take the payment back.
+    pub refund_deadline: u64,
+    pub status: OfferStatus,
+}
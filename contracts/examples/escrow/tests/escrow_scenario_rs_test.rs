@@ -0,0 +1,38 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/escrow");
+    blockchain.register_contract("mxsc:output/escrow.mxsc.json", escrow::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn escrow_cancel_rs() {
+    world().run("scenarios/escrow-cancel.scen.json");
+}
+
+#[test]
+fn escrow_create_rs() {
+    world().run("scenarios/escrow-create.scen.json");
+}
+
+#[test]
+fn escrow_dispute_rs() {
+    world().run("scenarios/escrow-dispute.scen.json");
+}
+
+#[test]
+fn escrow_dispute_release_rs() {
+    world().run("scenarios/escrow-dispute-release.scen.json");
+}
+
+#[test]
+fn escrow_refund_rs() {
+    world().run("scenarios/escrow-refund.scen.json");
+}
+
+#[test]
+fn escrow_release_rs() {
+    world().run("scenarios/escrow-release.scen.json");
+}
@@ -0,0 +1,35 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            9
+// Async Callback (empty):               1
+// Total number of exported functions:  12
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    escrow
+    (
+        init => init
+        upgrade => upgrade
+        create => create
+        release => release
+        refund => refund
+        cancel => cancel
+        dispute => dispute
+        resolveDispute => resolve_dispute
+        getOffer => get_offer
+        getOpenOffers => get_open_offers
+        getLastOfferId => last_offer_id
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
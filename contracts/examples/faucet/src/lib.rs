@@ -0,0 +1,147 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod faucet_proxy;
+
+pub const ERR_COOLDOWN: &str = "claim cooldown active";
+pub const ERR_BALANCE_EXHAUSTED: &str = "faucet balance exhausted";
+pub const ERR_CAP_REACHED: &str = "total cap reached";
+pub const ERR_NO_TOKENS: &str = "no tokens configured";
+
+/// Localnet faucet: hands out a configured amount of EGLD and/or ESDTs per claim,
+/// at most once every `cooldownRounds` rounds per receiver, up to a total cap per token.
+#[multiversx_sc::contract]
+pub trait Faucet {
+    #[init]
+    fn init(&self, cooldown_rounds: u64) {
+        self.cooldown_rounds().set(cooldown_rounds);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Sends every configured token to `opt_receiver`, or to the caller.
+    /// The cooldown is tracked per receiver, so anyone can pay the gas for a fresh wallet.
+    #[endpoint]
+    fn claim(&self, opt_receiver: OptionalValue<ManagedAddress>) {
+        let receiver = opt_receiver
+            .into_option()
+            .unwrap_or_else(|| self.blockchain().get_caller());
+        require!(!self.tokens().is_empty(), ERR_NO_TOKENS);
+
+        let current_round = self.blockchain().get_block_round();
+        require!(
+            current_round >= self.next_claim_round(receiver.clone()),
+            ERR_COOLDOWN
+        );
+        self.last_claim_round(&receiver).set(current_round);
+
+        for token in self.tokens().iter() {
+            let amount = self.amount_per_claim(&token).get();
+            let distributed = self.distributed(&token).get() + &amount;
+            require!(distributed <= self.total_cap(&token).get(), ERR_CAP_REACHED);
+
+            let balance = self.blockchain().get_sc_balance(&token, 0);
+            require!(balance >= amount, ERR_BALANCE_EXHAUSTED);
+
+            self.distributed(&token).set(distributed);
+            self.tx()
+                .to(&receiver)
+                .egld_or_single_esdt(&token, 0, &amount)
+                .transfer();
+        }
+    }
+
+    /// Tops up the faucet with EGLD or a single ESDT.
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint]
+    fn refill(&self) {
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.amount > 0, "nothing to refill");
+    }
+
+    /// Sends `opt_amount` (the whole balance by default) of `token` back to the owner.
+    #[only_owner]
+    #[endpoint]
+    fn drain(&self, token: EgldOrEsdtTokenIdentifier, opt_amount: OptionalValue<BigUint>) {
+        let balance = self.blockchain().get_sc_balance(&token, 0);
+        let amount = opt_amount.into_option().unwrap_or(balance.clone());
+        require!(amount <= balance, ERR_BALANCE_EXHAUSTED);
+
+        let owner = self.blockchain().get_owner_address();
+        self.tx()
+            .to(&owner)
+            .egld_or_single_esdt(&token, 0, &amount)
+            .transfer_if_not_empty();
+    }
+
+    /// Adds `token` to the claim, or changes its amount and cap.
+    #[only_owner]
+    #[endpoint(setToken)]
+    fn set_token(
+        &self,
+        token: EgldOrEsdtTokenIdentifier,
+        amount_per_claim: BigUint,
+        total_cap: BigUint,
+    ) {
+        require!(token.is_valid(), "invalid token identifier");
+        require!(amount_per_claim > 0, "amount per claim cannot be zero");
+
+        self.tokens().insert(token.clone());
+        self.amount_per_claim(&token).set(amount_per_claim);
+        self.total_cap(&token).set(total_cap);
+    }
+
+    #[only_owner]
+    #[endpoint(removeToken)]
+    fn remove_token(&self, token: EgldOrEsdtTokenIdentifier) {
+        self.tokens().swap_remove(&token);
+        self.amount_per_claim(&token).clear();
+        self.total_cap(&token).clear();
+    }
+
+    #[only_owner]
+    #[endpoint(setCooldownRounds)]
+    fn set_cooldown_rounds(&self, cooldown_rounds: u64) {
+        self.cooldown_rounds().set(cooldown_rounds);
+    }
+
+    /// First round in which `address` may claim again. Saturates, so a huge cooldown disables claims.
+    #[view(getNextClaimRound)]
+    fn next_claim_round(&self, address: ManagedAddress) -> u64 {
+        let last_claim_round = self.last_claim_round(&address);
+        if last_claim_round.is_empty() {
+            return 0;
+        }
+
+        last_claim_round
+            .get()
+            .saturating_add(self.cooldown_rounds().get())
+    }
+
+    #[view(getCooldownRounds)]
+    #[storage_mapper("cooldownRounds")]
+    fn cooldown_rounds(&self) -> SingleValueMapper<u64>;
+
+    #[view(getTokens)]
+    #[storage_mapper("tokens")]
+    fn tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getAmountPerClaim)]
+    #[storage_mapper("amountPerClaim")]
+    fn amount_per_claim(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalCap)]
+    #[storage_mapper("totalCap")]
+    fn total_cap(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getDistributed)]
+    #[storage_mapper("distributed")]
+    fn distributed(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getLastClaimRound)]
+    #[storage_mapper("lastClaimRound")]
+    fn last_claim_round(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,28 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/faucet");
+    blockchain.register_contract("mxsc:output/faucet.mxsc.json", faucet::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn faucet_admin_rs() {
+    world().run("scenarios/faucet-admin.scen.json");
+}
+
+#[test]
+fn faucet_claim_rs() {
+    world().run("scenarios/faucet-claim.scen.json");
+}
+
+#[test]
+fn faucet_claim_cooldown_rs() {
+    world().run("scenarios/faucet-claim-cooldown.scen.json");
+}
+
+#[test]
+fn faucet_claim_exhausted_rs() {
+    world().run("scenarios/faucet-claim-exhausted.scen.json");
+}
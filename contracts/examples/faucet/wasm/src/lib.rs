@@ -0,0 +1,39 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           13
+// Async Callback (empty):               1
+// Total number of exported functions:  16
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    faucet
+    (
+        init => init
+        upgrade => upgrade
+        claim => claim
+        refill => refill
+        drain => drain
+        setToken => set_token
+        removeToken => remove_token
+        setCooldownRounds => set_cooldown_rounds
+        getNextClaimRound => next_claim_round
+        getCooldownRounds => cooldown_rounds
+        getTokens => tokens
+        getAmountPerClaim => amount_per_claim
+        getTotalCap => total_cap
+        getDistributed => distributed
+        getLastClaimRound => last_claim_round
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,28 @@
+multiversx_sc::imports!();
+
+use crate::proposal::VoteType;
+
+/// Proposal lifecycle events, with the proposal id as the first topic.
+#[multiversx_sc::module]
+pub trait GovernanceEventsModule {
+    #[event("proposalCreated")]
+    fn proposal_created_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] proposer: &ManagedAddress,
+        #[indexed] voting_end: u64,
+        description: &ManagedBuffer,
+    );
+
+    #[event("voteCast")]
+    fn vote_cast_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] vote_type: VoteType,
+        amount: &BigUint,
+    );
+
+    #[event("proposalExecuted")]
+    fn proposal_executed_event(&self, #[indexed] proposal_id: u64);
+}
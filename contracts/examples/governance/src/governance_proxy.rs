@@ -0,0 +1,326 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct GovernanceProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for GovernanceProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = GovernanceProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        GovernanceProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct GovernanceProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> GovernanceProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+    >(
+        self,
+        governance_token: Arg0,
+        quorum: Arg1,
+        min_proposal_deposit: Arg2,
+        voting_period: Arg3,
+        timelock: Arg4,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&governance_token)
+            .argument(&quorum)
+            .argument(&min_proposal_deposit)
+            .argument(&voting_period)
+            .argument(&timelock)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> GovernanceProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> GovernanceProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Opens a proposal. The deposit is locked as the proposer's yes vote. Returns the proposal id. 
+    pub fn propose<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, GovernanceAction<Env::Api>>>,
+    >(
+        self,
+        description: Arg0,
+        actions: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("propose")
+            .argument(&description)
+            .argument(&actions)
+            .original_result()
+    }
+
+    /// Locks the paid governance tokens on the proposal as votes. Can be called more than once. 
+    pub fn vote<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<VoteType>,
+    >(
+        self,
+        proposal_id: Arg0,
+        vote_type: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("vote")
+            .argument(&proposal_id)
+            .argument(&vote_type)
+            .original_result()
+    }
+
+    /// Runs the actions of a passed proposal whose timelock is over. 
+    pub fn execute<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("execute")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Returns the caller's tokens locked on a proposal whose voting is over. 
+    pub fn withdraw<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdraw")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_status<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProposalStatus> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalStatus")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Proposal<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal_actions<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, GovernanceAction<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalActions")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn governance_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGovernanceToken")
+            .original_result()
+    }
+
+    /// Minimum of yes, no and abstain votes together for a proposal to pass. 
+    pub fn quorum(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuorum")
+            .original_result()
+    }
+
+    pub fn min_proposal_deposit(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinProposalDeposit")
+            .original_result()
+    }
+
+    /// Seconds from proposal creation until voting ends. 
+    pub fn voting_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVotingPeriod")
+            .original_result()
+    }
+
+    /// Seconds from the end of voting until a passed proposal can be executed. 
+    pub fn timelock(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTimelock")
+            .original_result()
+    }
+
+    pub fn last_proposal_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastProposalId")
+            .original_result()
+    }
+
+    /// Governance tokens `voter` has locked on the proposal. 
+    pub fn locked_votes<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        voter: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLockedVotes")
+            .argument(&proposal_id)
+            .argument(&voter)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, PartialEq, Debug)]
+pub enum GovernanceAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Call {
+        to: ManagedAddress<Api>,
+        endpoint_name: ManagedBuffer<Api>,
+        arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+    },
+    UpgradeFromSource {
+        sc_address: ManagedAddress<Api>,
+        source: ManagedAddress<Api>,
+        code_metadata: CodeMetadata,
+        arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+    },
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum VoteType {
+    Yes,
+    No,
+    Abstain,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum ProposalStatus {
+    Active,
+    Defeated,
+    Queued,
+    Executable,
+    Executed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Proposal<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub proposer: ManagedAddress<Api>,
+    pub description: ManagedBuffer<Api>,
+    pub created_at: u64,
+    pub voting_end: u64,
+    pub yes_votes: BigUint<Api>,
+    pub no_votes: BigUint<Api>,
+    pub abstain_votes: BigUint<Api>,
+    pub executed: bool,
+}
@@ -0,0 +1,312 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod governance_events;
+pub mod governance_proxy;
+pub mod proposal;
+
+use proposal::{GovernanceAction, Proposal, ProposalStatus, VoteType};
+
+pub const ERR_INVALID_TOKEN: &str = "invalid token";
+pub const ERR_ZERO_VOTING_PERIOD: &str = "voting period cannot be zero";
+pub const ERR_VOTING_PERIOD_TOO_LONG: &str = "voting period cannot exceed one year";
+pub const ERR_TIMELOCK_TOO_LONG: &str = "timelock cannot exceed one year";
+pub const ERR_WRONG_TOKEN: &str = "wrong token";
+pub const ERR_ZERO_AMOUNT: &str = "amount cannot be zero";
+pub const ERR_DEPOSIT_TOO_LOW: &str = "deposit below the minimum for proposing";
+pub const ERR_NO_ACTIONS: &str = "proposal has no actions";
+pub const ERR_TOO_MANY_ACTIONS: &str = "too many actions";
+pub const ERR_UPGRADE_NOT_LAST: &str = "an upgrade can only be the last action";
+pub const ERR_PROPOSAL_NOT_FOUND: &str = "proposal does not exist";
+pub const ERR_VOTING_CLOSED: &str = "voting period is over";
+pub const ERR_VOTING_OPEN: &str = "voting period is not over";
+pub const ERR_NOT_EXECUTABLE: &str = "proposal cannot be executed";
+pub const ERR_NOTHING_TO_WITHDRAW: &str = "no tokens locked on this proposal";
+
+pub const MAX_ACTIONS: usize = 10;
+/// Upper bound, in seconds, for both the voting period and the timelock.
+pub const MAX_PERIOD: u64 = 365 * 24 * 60 * 60;
+
+/// Gas kept back for finishing `execute` after each call.
+const EXECUTE_FINISH_GAS: u64 = 300_000;
+
+/// Holders of the governance token propose lists of SC calls and vote on them by locking tokens.
+/// A proposal passes with at least `quorum` tokens voted and more yes than no votes; it can be
+/// executed by anyone `timelock` seconds after voting ends. Locked tokens are withdrawn once voting ends.
+#[multiversx_sc::contract]
+pub trait Governance: governance_events::GovernanceEventsModule {
+    #[init]
+    fn init(
+        &self,
+        governance_token: TokenIdentifier,
+        quorum: BigUint,
+        min_proposal_deposit: BigUint,
+        voting_period: u64,
+        timelock: u64,
+    ) {
+        require!(
+            governance_token.is_valid_esdt_identifier(),
+            ERR_INVALID_TOKEN
+        );
+        require!(voting_period > 0, ERR_ZERO_VOTING_PERIOD);
+        require!(voting_period <= MAX_PERIOD, ERR_VOTING_PERIOD_TOO_LONG);
+        require!(timelock <= MAX_PERIOD, ERR_TIMELOCK_TOO_LONG);
+
+        self.governance_token().set(governance_token);
+        self.quorum().set(quorum);
+        self.min_proposal_deposit().set(min_proposal_deposit);
+        self.voting_period().set(voting_period);
+        self.timelock().set(timelock);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Opens a proposal. The deposit is locked as the proposer's yes vote. Returns the proposal id.
+    #[payable("*")]
+    #[endpoint]
+    fn propose(
+        &self,
+        description: ManagedBuffer,
+        actions: MultiValueEncoded<GovernanceAction<Self::Api>>,
+    ) -> u64 {
+        let amount = self.require_governance_payment();
+        require!(
+            amount >= self.min_proposal_deposit().get(),
+            ERR_DEPOSIT_TOO_LOW
+        );
+
+        let proposer = self.blockchain().get_caller();
+        let now = self.blockchain().get_block_timestamp();
+        let proposal_id = self.last_proposal_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let proposal = Proposal {
+            proposer: proposer.clone(),
+            description,
+            created_at: now,
+            voting_end: now.saturating_add(self.voting_period().get()),
+            yes_votes: BigUint::zero(),
+            no_votes: BigUint::zero(),
+            abstain_votes: BigUint::zero(),
+            executed: false,
+        };
+        self.proposal_created_event(
+            proposal_id,
+            &proposer,
+            proposal.voting_end,
+            &proposal.description,
+        );
+        self.proposals().insert(proposal_id, proposal);
+
+        let mut proposal_actions = self.proposal_actions(proposal_id);
+        let mut follows_upgrade = false;
+        for action in actions {
+            require!(!follows_upgrade, ERR_UPGRADE_NOT_LAST);
+            follows_upgrade = matches!(action, GovernanceAction::UpgradeFromSource { .. });
+            proposal_actions.push(&action);
+        }
+        require!(!proposal_actions.is_empty(), ERR_NO_ACTIONS);
+        require!(proposal_actions.len() <= MAX_ACTIONS, ERR_TOO_MANY_ACTIONS);
+
+        self.add_vote(proposal_id, &proposer, VoteType::Yes, amount);
+        proposal_id
+    }
+
+    /// Locks the paid governance tokens on the proposal as votes. Can be called more than once.
+    #[payable("*")]
+    #[endpoint]
+    fn vote(&self, proposal_id: u64, vote_type: VoteType) {
+        let amount = self.require_governance_payment();
+        let proposal = self.require_proposal(proposal_id);
+        require!(
+            self.blockchain().get_block_timestamp() < proposal.voting_end,
+            ERR_VOTING_CLOSED
+        );
+
+        let voter = self.blockchain().get_caller();
+        self.add_vote(proposal_id, &voter, vote_type, amount);
+    }
+
+    /// Runs the actions of a passed proposal whose timelock is over.
+    #[endpoint]
+    fn execute(&self, proposal_id: u64) {
+        require!(
+            self.get_proposal_status(proposal_id) == ProposalStatus::Executable,
+            ERR_NOT_EXECUTABLE
+        );
+        // marked before any outgoing call, so the proposal cannot be executed twice
+        let mut proposal = self.require_proposal(proposal_id);
+        proposal.executed = true;
+        self.proposals().insert(proposal_id, proposal);
+        self.proposal_executed_event(proposal_id);
+
+        for action in self.proposal_actions(proposal_id).iter() {
+            self.perform_action(action);
+        }
+    }
+
+    /// Returns the caller's tokens locked on a proposal whose voting is over.
+    #[endpoint]
+    fn withdraw(&self, proposal_id: u64) -> BigUint {
+        let proposal = self.require_proposal(proposal_id);
+        require!(
+            self.blockchain().get_block_timestamp() >= proposal.voting_end,
+            ERR_VOTING_OPEN
+        );
+
+        let caller = self.blockchain().get_caller();
+        let amount = self.locked_votes(proposal_id, &caller).take();
+        require!(amount > 0, ERR_NOTHING_TO_WITHDRAW);
+
+        self.tx()
+            .to(&caller)
+            .single_esdt(&self.governance_token().get(), 0, &amount)
+            .transfer();
+        amount
+    }
+
+    fn perform_action(&self, action: GovernanceAction<Self::Api>) {
+        match action {
+            GovernanceAction::Call {
+                to,
+                endpoint_name,
+                arguments,
+            } => {
+                let gas = self.blockchain().get_gas_left();
+                require!(gas > EXECUTE_FINISH_GAS, "insufficient gas for call");
+                self.tx()
+                    .to(to)
+                    .gas(gas - EXECUTE_FINISH_GAS)
+                    .raw_call(endpoint_name)
+                    .arguments_raw(arguments.into())
+                    .sync_call();
+            }
+            GovernanceAction::UpgradeFromSource {
+                sc_address,
+                source,
+                code_metadata,
+                arguments,
+            } => {
+                let gas_left = self.blockchain().get_gas_left();
+                self.tx()
+                    .to(sc_address)
+                    .gas(gas_left)
+                    .raw_upgrade()
+                    .from_source(source)
+                    .code_metadata(code_metadata)
+                    .arguments_raw(arguments.into())
+                    .upgrade_async_call_and_exit();
+            }
+        }
+    }
+
+    fn require_governance_payment(&self) -> BigUint {
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.governance_token().get() && payment.token_nonce == 0,
+            ERR_WRONG_TOKEN
+        );
+        require!(payment.amount > 0, ERR_ZERO_AMOUNT);
+        payment.amount.clone()
+    }
+
+    fn require_proposal(&self, proposal_id: u64) -> Proposal<Self::Api> {
+        self.proposals()
+            .get(&proposal_id)
+            .unwrap_or_else(|| sc_panic!(ERR_PROPOSAL_NOT_FOUND))
+    }
+
+    fn add_vote(
+        &self,
+        proposal_id: u64,
+        voter: &ManagedAddress,
+        vote_type: VoteType,
+        amount: BigUint,
+    ) {
+        let mut proposal = self.require_proposal(proposal_id);
+        match vote_type {
+            VoteType::Yes => proposal.yes_votes += &amount,
+            VoteType::No => proposal.no_votes += &amount,
+            VoteType::Abstain => proposal.abstain_votes += &amount,
+        }
+        self.proposals().insert(proposal_id, proposal);
+        self.locked_votes(proposal_id, voter)
+            .update(|locked| *locked += &amount);
+
+        self.vote_cast_event(proposal_id, voter, vote_type, &amount);
+    }
+
+    #[view(getProposalStatus)]
+    fn get_proposal_status(&self, proposal_id: u64) -> ProposalStatus {
+        let proposal = self.require_proposal(proposal_id);
+        let now = self.blockchain().get_block_timestamp();
+        if proposal.executed {
+            return ProposalStatus::Executed;
+        }
+        if now < proposal.voting_end {
+            return ProposalStatus::Active;
+        }
+        if proposal.total_votes() < self.quorum().get() || proposal.yes_votes <= proposal.no_votes {
+            return ProposalStatus::Defeated;
+        }
+        if now < proposal.voting_end.saturating_add(self.timelock().get()) {
+            return ProposalStatus::Queued;
+        }
+        ProposalStatus::Executable
+    }
+
+    #[view(getProposal)]
+    fn get_proposal(&self, proposal_id: u64) -> Proposal<Self::Api> {
+        self.require_proposal(proposal_id)
+    }
+
+    #[view(getProposalActions)]
+    fn get_proposal_actions(
+        &self,
+        proposal_id: u64,
+    ) -> MultiValueEncoded<GovernanceAction<Self::Api>> {
+        self.require_proposal(proposal_id);
+        self.proposal_actions(proposal_id).iter().collect()
+    }
+
+    #[view(getGovernanceToken)]
+    #[storage_mapper("governanceToken")]
+    fn governance_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    /// Minimum of yes, no and abstain votes together for a proposal to pass.
+    #[view(getQuorum)]
+    #[storage_mapper("quorum")]
+    fn quorum(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getMinProposalDeposit)]
+    #[storage_mapper("minProposalDeposit")]
+    fn min_proposal_deposit(&self) -> SingleValueMapper<BigUint>;
+
+    /// Seconds from proposal creation until voting ends.
+    #[view(getVotingPeriod)]
+    #[storage_mapper("votingPeriod")]
+    fn voting_period(&self) -> SingleValueMapper<u64>;
+
+    /// Seconds from the end of voting until a passed proposal can be executed.
+    #[view(getTimelock)]
+    #[storage_mapper("timelock")]
+    fn timelock(&self) -> SingleValueMapper<u64>;
+
+    #[view(getLastProposalId)]
+    #[storage_mapper("lastProposalId")]
+    fn last_proposal_id(&self) -> SingleValueMapper<u64>;
+
+    /// Governance tokens `voter` has locked on the proposal.
+    #[view(getLockedVotes)]
+    #[storage_mapper("lockedVotes")]
+    fn locked_votes(&self, proposal_id: u64, voter: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("proposals")]
+    fn proposals(&self) -> MapMapper<u64, Proposal<Self::Api>>;
+
+    #[storage_mapper("proposalActions")]
+    fn proposal_actions(&self, proposal_id: u64) -> VecMapper<GovernanceAction<Self::Api>>;
+}
@@ -0,0 +1,62 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// One step of a proposal, run by the governance contract itself once the proposal passes.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, PartialEq, Debug)]
+pub enum GovernanceAction<M: ManagedTypeApi> {
+    /// Synchronous call to `endpoint_name` on `to`.
+    Call {
+        to: ManagedAddress<M>,
+        endpoint_name: ManagedBuffer<M>,
+        arguments: ManagedVec<M, ManagedBuffer<M>>,
+    },
+    /// Upgrades a contract owned by governance with the code of `source`. Only allowed as the last action.
+    UpgradeFromSource {
+        sc_address: ManagedAddress<M>,
+        source: ManagedAddress<M>,
+        code_metadata: CodeMetadata,
+        arguments: ManagedVec<M, ManagedBuffer<M>>,
+    },
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum VoteType {
+    Yes,
+    No,
+    /// Counts towards the quorum only.
+    Abstain,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum ProposalStatus {
+    Active,
+    /// Voting is over without quorum, or without more yes than no votes.
+    Defeated,
+    /// Passed, waiting for the timelock.
+    Queued,
+    Executable,
+    Executed,
+}
+
+/// Vote tallies are in governance tokens locked on the proposal.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Proposal<M: ManagedTypeApi> {
+    pub proposer: ManagedAddress<M>,
+    pub description: ManagedBuffer<M>,
+    pub created_at: u64,
+    pub voting_end: u64,
+    pub yes_votes: BigUint<M>,
+    pub no_votes: BigUint<M>,
+    pub abstain_votes: BigUint<M>,
+    pub executed: bool,
+}
+
+impl<M: ManagedTypeApi> Proposal<M> {
+    pub fn total_votes(&self) -> BigUint<M> {
+        &self.yes_votes + &self.no_votes + &self.abstain_votes
+    }
+}
@@ -0,0 +1,39 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/governance");
+    blockchain.register_contract(
+        "mxsc:output/governance.mxsc.json",
+        governance::ContractBuilder,
+    );
+    blockchain.register_contract(
+        "mxsc:../adder/output/adder.mxsc.json",
+        adder::ContractBuilder,
+    );
+    blockchain.register_contract(
+        "mxsc:../counter/output/counter.mxsc.json",
+        counter::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn governance_defeated_rs() {
+    world().run("scenarios/governance-defeated.scen.json");
+}
+
+#[test]
+fn governance_lifecycle_rs() {
+    world().run("scenarios/governance-lifecycle.scen.json");
+}
+
+#[test]
+fn governance_propose_rs() {
+    world().run("scenarios/governance-propose.scen.json");
+}
+
+#[test]
+fn governance_setup_rs() {
+    world().run("scenarios/governance-setup.scen.json");
+}
@@ -0,0 +1,40 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           14
+// Async Callback (empty):               1
+// Total number of exported functions:  17
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    governance
+    (
+        init => init
+        upgrade => upgrade
+        propose => propose
+        vote => vote
+        execute => execute
+        withdraw => withdraw
+        getProposalStatus => get_proposal_status
+        getProposal => get_proposal
+        getProposalActions => get_proposal_actions
+        getGovernanceToken => governance_token
+        getQuorum => quorum
+        getMinProposalDeposit => min_proposal_deposit
+        getVotingPeriod => voting_period
+        getTimelock => timelock
+        getLastProposalId => last_proposal_id
+        getLockedVotes => locked_votes
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
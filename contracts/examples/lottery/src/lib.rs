@@ -0,0 +1,249 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod lottery_events;
+pub mod lottery_info;
+pub mod lottery_proxy;
+
+use lottery_info::{LotteryInfo, LotteryStatus};
+
+pub const ERR_LOTTERY_EXISTS: &str = "lottery already exists";
+pub const ERR_INVALID_TOKEN: &str = "invalid token";
+pub const ERR_ZERO_PRICE: &str = "ticket price cannot be zero";
+pub const ERR_DEADLINE_IN_PAST: &str = "deadline must be in the future";
+pub const ERR_ZERO_MAX_ENTRIES: &str = "max tickets per user cannot be zero";
+pub const ERR_INVALID_MAX_TOTAL: &str = "max total tickets must be between 1 and 1000";
+pub const ERR_INVALID_DISTRIBUTION: &str =
+    "prize distribution must have between 1 and 10 non-zero percentages adding up to 100";
+pub const ERR_LOTTERY_NOT_FOUND: &str = "lottery does not exist";
+pub const ERR_LOTTERY_ENDED: &str = "lottery has ended";
+pub const ERR_LOTTERY_RUNNING: &str = "lottery is still running";
+pub const ERR_WRONG_PAYMENT: &str = "payment must be exactly one ticket price in the lottery token";
+pub const ERR_TICKET_LIMIT: &str = "ticket limit reached";
+pub const ERR_SOLD_OUT: &str = "all tickets sold";
+
+pub const PERCENTAGE_TOTAL: u8 = 100;
+pub const MAX_WINNERS: usize = 10;
+/// Bounds the ticket holder list that `determineWinner` walks to clear the per-user entries.
+pub const MAX_TOTAL_TICKETS: usize = 1_000;
+
+/// Runs any number of named lotteries side by side. Players buy tickets until the deadline,
+/// then anyone can draw the winners with the block randomness. Each drawn ticket wins the
+/// next place's percentage of the prize pool; a player holding several tickets can win several places.
+#[multiversx_sc::contract]
+pub trait Lottery: lottery_events::LotteryEventsModule {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[only_owner]
+    #[endpoint]
+    fn start(
+        &self,
+        lottery_name: ManagedBuffer,
+        token_identifier: EgldOrEsdtTokenIdentifier,
+        ticket_price: BigUint,
+        deadline: u64,
+        max_entries_per_user: usize,
+        max_total_tickets: usize,
+        prize_distribution: ManagedVec<u8>,
+    ) {
+        require!(
+            !self.lottery_names().contains(&lottery_name),
+            ERR_LOTTERY_EXISTS
+        );
+        require!(token_identifier.is_valid(), ERR_INVALID_TOKEN);
+        require!(ticket_price > 0, ERR_ZERO_PRICE);
+        require!(
+            deadline > self.blockchain().get_block_timestamp(),
+            ERR_DEADLINE_IN_PAST
+        );
+        require!(max_entries_per_user > 0, ERR_ZERO_MAX_ENTRIES);
+        require!(
+            max_total_tickets > 0 && max_total_tickets <= MAX_TOTAL_TICKETS,
+            ERR_INVALID_MAX_TOTAL
+        );
+        self.require_valid_distribution(&prize_distribution);
+
+        self.lottery_names().insert(lottery_name.clone());
+        self.lottery_info(&lottery_name).set(LotteryInfo {
+            token_identifier,
+            ticket_price,
+            deadline,
+            max_entries_per_user,
+            max_total_tickets,
+            prize_distribution,
+            prize_pool: BigUint::zero(),
+        });
+        self.lottery_started_event(&lottery_name, deadline);
+    }
+
+    /// Buys one ticket for exactly the ticket price, while the lottery is not sold out.
+    #[payable("*")]
+    #[endpoint(buyTicket)]
+    fn buy_ticket(&self, lottery_name: ManagedBuffer) {
+        match self.status(lottery_name.clone()) {
+            LotteryStatus::Inactive => sc_panic!(ERR_LOTTERY_NOT_FOUND),
+            LotteryStatus::Running => {}
+            LotteryStatus::Ended => sc_panic!(ERR_LOTTERY_ENDED),
+        }
+
+        let mut info = self.lottery_info(&lottery_name).get();
+        let (token_identifier, amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(
+            token_identifier == info.token_identifier && amount == info.ticket_price,
+            ERR_WRONG_PAYMENT
+        );
+
+        require!(
+            self.ticket_holders(&lottery_name).len() < info.max_total_tickets,
+            ERR_SOLD_OUT
+        );
+
+        let caller = self.blockchain().get_caller();
+        let entries = self.entries_for_user(&lottery_name, &caller);
+        require!(entries.get() < info.max_entries_per_user, ERR_TICKET_LIMIT);
+        entries.update(|entries| *entries += 1);
+
+        let ticket_index = self.ticket_holders(&lottery_name).push(&caller);
+        info.prize_pool += amount;
+        self.lottery_info(&lottery_name).set(info);
+        self.ticket_bought_event(&lottery_name, &caller, ticket_index);
+    }
+
+    /// Draws the winners of an ended lottery, pays them and frees its name.
+    /// With fewer tickets than places, the places without a ticket are not drawn;
+    /// whatever is left of the pool, rounding dust included, goes to the first place.
+    #[endpoint(determineWinner)]
+    fn determine_winner(&self, lottery_name: ManagedBuffer) {
+        match self.status(lottery_name.clone()) {
+            LotteryStatus::Inactive => sc_panic!(ERR_LOTTERY_NOT_FOUND),
+            LotteryStatus::Running => sc_panic!(ERR_LOTTERY_RUNNING),
+            LotteryStatus::Ended => {}
+        }
+
+        let info = self.lottery_info(&lottery_name).get();
+        let winners = self.draw_winners(&lottery_name, info.prize_distribution.len());
+        if !winners.is_empty() {
+            let mut prizes = ManagedVec::<Self::Api, BigUint>::new();
+            let mut remainder = info.prize_pool.clone();
+            for place in 0..winners.len() {
+                let percentage = info.prize_distribution.get(place);
+                let prize = &info.prize_pool * percentage as u32 / PERCENTAGE_TOTAL as u32;
+                remainder -= &prize;
+                prizes.push(prize);
+            }
+
+            for (place, winner) in winners.iter().enumerate() {
+                let mut prize = prizes.get(place).clone();
+                if place == 0 {
+                    prize += &remainder;
+                }
+                self.lottery_winner_event(&lottery_name, &winner, place + 1, &prize);
+                self.tx()
+                    .to(&*winner)
+                    .egld_or_single_esdt(&info.token_identifier, 0, &prize)
+                    .transfer_if_not_empty();
+            }
+        }
+
+        self.clear_lottery(&lottery_name);
+    }
+
+    /// Picks `count` distinct tickets, at most one per ticket sold, by a partial shuffle
+    /// of the ticket holders. Returns their holders in place order.
+    fn draw_winners(
+        &self,
+        lottery_name: &ManagedBuffer,
+        count: usize,
+    ) -> ManagedVec<ManagedAddress> {
+        let mut ticket_holders = self.ticket_holders(lottery_name);
+        let ticket_count = ticket_holders.len();
+        let mut rand_source = RandomnessSource::new();
+        let mut winners = ManagedVec::new();
+        for index in 1..=count.min(ticket_count) {
+            let picked = rand_source.next_usize_in_range(index, ticket_count + 1);
+            let winner = ticket_holders.get(picked);
+            if picked != index {
+                let displaced = ticket_holders.get(index);
+                ticket_holders.set(picked, &displaced);
+                ticket_holders.set(index, &winner);
+            }
+            winners.push(winner);
+        }
+        winners
+    }
+
+    fn clear_lottery(&self, lottery_name: &ManagedBuffer) {
+        let mut ticket_holders = self.ticket_holders(lottery_name);
+        for holder in ticket_holders.iter() {
+            self.entries_for_user(lottery_name, &holder).clear();
+        }
+        ticket_holders.clear();
+        self.lottery_info(lottery_name).clear();
+        self.lottery_names().swap_remove(lottery_name);
+    }
+
+    fn require_valid_distribution(&self, prize_distribution: &ManagedVec<u8>) {
+        require!(
+            !prize_distribution.is_empty() && prize_distribution.len() <= MAX_WINNERS,
+            ERR_INVALID_DISTRIBUTION
+        );
+        let mut total = 0u32;
+        for percentage in prize_distribution.iter() {
+            require!(percentage > 0, ERR_INVALID_DISTRIBUTION);
+            total += percentage as u32;
+        }
+        require!(total == PERCENTAGE_TOTAL as u32, ERR_INVALID_DISTRIBUTION);
+    }
+
+    #[view]
+    fn status(&self, lottery_name: ManagedBuffer) -> LotteryStatus {
+        let info = self.lottery_info(&lottery_name);
+        if info.is_empty() {
+            LotteryStatus::Inactive
+        } else if self.blockchain().get_block_timestamp() < info.get().deadline {
+            LotteryStatus::Running
+        } else {
+            LotteryStatus::Ended
+        }
+    }
+
+    #[view(getLotteryInfo)]
+    fn get_lottery_info(&self, lottery_name: ManagedBuffer) -> LotteryInfo<Self::Api> {
+        let info = self.lottery_info(&lottery_name);
+        require!(!info.is_empty(), ERR_LOTTERY_NOT_FOUND);
+        info.get()
+    }
+
+    #[view(getTicketCount)]
+    fn get_ticket_count(&self, lottery_name: ManagedBuffer) -> usize {
+        self.ticket_holders(&lottery_name).len()
+    }
+
+    /// Lotteries that were started and not drawn yet.
+    #[view(getLotteryNames)]
+    #[storage_mapper("lotteryNames")]
+    fn lottery_names(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("lotteryInfo")]
+    fn lottery_info(
+        &self,
+        lottery_name: &ManagedBuffer,
+    ) -> SingleValueMapper<LotteryInfo<Self::Api>>;
+
+    /// One entry per ticket sold.
+    #[storage_mapper("ticketHolders")]
+    fn ticket_holders(&self, lottery_name: &ManagedBuffer) -> VecMapper<ManagedAddress>;
+
+    #[view(getUserTicketCount)]
+    #[storage_mapper("entriesForUser")]
+    fn entries_for_user(
+        &self,
+        lottery_name: &ManagedBuffer,
+        user: &ManagedAddress,
+    ) -> SingleValueMapper<usize>;
+}
@@ -0,0 +1,29 @@
+multiversx_sc::imports!();
+
+/// Lottery lifecycle events, with the lottery name as the first topic.
+#[multiversx_sc::module]
+pub trait LotteryEventsModule {
+    #[event("lotteryStarted")]
+    fn lottery_started_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] deadline: u64,
+    );
+
+    #[event("ticketBought")]
+    fn ticket_bought_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] buyer: &ManagedAddress,
+        ticket_index: usize,
+    );
+
+    #[event("lotteryWinner")]
+    fn lottery_winner_event(
+        &self,
+        #[indexed] lottery_name: &ManagedBuffer,
+        #[indexed] winner: &ManagedAddress,
+        #[indexed] place: usize,
+        prize: &BigUint,
+    );
+}
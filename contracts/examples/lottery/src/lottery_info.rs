@@ -0,0 +1,28 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum LotteryStatus {
+    /// No lottery with this name, or its winners were already drawn.
+    Inactive,
+    Running,
+    /// Past the deadline, waiting for `determineWinner`.
+    Ended,
+}
+
+/// Settings and prize pool of one named lottery.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct LotteryInfo<M: ManagedTypeApi> {
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub ticket_price: BigUint<M>,
+    /// Tickets can be bought until this block timestamp.
+    pub deadline: u64,
+    pub max_entries_per_user: usize,
+    /// Tickets sold across all players stop at this count.
+    pub max_total_tickets: usize,
+    /// Percentage of the prize pool for each winner, first place first. Adds up to 100.
+    pub prize_distribution: ManagedVec<M, u8>,
+    pub prize_pool: BigUint<M>,
+}
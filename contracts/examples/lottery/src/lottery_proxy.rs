@@ -0,0 +1,231 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct LotteryProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for LotteryProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = LotteryProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        LotteryProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct LotteryProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> LotteryProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init(
+        self,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> LotteryProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> LotteryProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn start<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<usize>,
+        Arg5: ProxyArg<usize>,
+        Arg6: ProxyArg<ManagedVec<Env::Api, u8>>,
+    >(
+        self,
+        lottery_name: Arg0,
+        token_identifier: Arg1,
+        ticket_price: Arg2,
+        deadline: Arg3,
+        max_entries_per_user: Arg4,
+        max_total_tickets: Arg5,
+        prize_distribution: Arg6,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("start")
+            .argument(&lottery_name)
+            .argument(&token_identifier)
+            .argument(&ticket_price)
+            .argument(&deadline)
+            .argument(&max_entries_per_user)
+            .argument(&max_total_tickets)
+            .argument(&prize_distribution)
+            .original_result()
+    }
+
+    /// Buys one ticket for exactly the ticket price, while the lottery is not sold out. 
+    pub fn buy_ticket<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("buyTicket")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// Draws the winners of an ended lottery, pays them and frees its name. 
+    /// With fewer tickets than places, the places without a ticket are not drawn; 
+    /// whatever is left of the pool, rounding dust included, goes to the first place. 
+    pub fn determine_winner<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("determineWinner")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn status<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, LotteryStatus> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("status")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn get_lottery_info<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, LotteryInfo<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLotteryInfo")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    pub fn get_ticket_count<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTicketCount")
+            .argument(&lottery_name)
+            .original_result()
+    }
+
+    /// Lotteries that were started and not drawn yet. 
+    pub fn lottery_names(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLotteryNames")
+            .original_result()
+    }
+
+    pub fn entries_for_user<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        lottery_name: Arg0,
+        user: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserTicketCount")
+            .argument(&lottery_name)
+            .argument(&user)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum LotteryStatus {
+    Inactive,
+    Running,
+    Ended,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct LotteryInfo<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_identifier: EgldOrEsdtTokenIdentifier<Api>,
+    pub ticket_price: BigUint<Api>,
+    pub deadline: u64,
+    pub max_entries_per_user: usize,
+    pub max_total_tickets: usize,
+    pub prize_distribution: ManagedVec<Api, u8>,
+    pub prize_pool: BigUint<Api>,
+}
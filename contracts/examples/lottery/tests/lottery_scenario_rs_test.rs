@@ -0,0 +1,28 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/lottery");
+    blockchain.register_contract("mxsc:output/lottery.mxsc.json", lottery::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn lottery_draw_rs() {
+    world().run("scenarios/lottery-draw.scen.json");
+}
+
+#[test]
+fn lottery_no_tickets_rs() {
+    world().run("scenarios/lottery-no-tickets.scen.json");
+}
+
+#[test]
+fn lottery_start_rs() {
+    world().run("scenarios/lottery-start.scen.json");
+}
+
+#[test]
+fn lottery_tickets_rs() {
+    world().run("scenarios/lottery-tickets.scen.json");
+}
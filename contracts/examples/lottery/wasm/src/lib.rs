@@ -0,0 +1,34 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            8
+// Async Callback (empty):               1
+// Total number of exported functions:  11
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    lottery
+    (
+        init => init
+        upgrade => upgrade
+        start => start
+        buyTicket => buy_ticket
+        determineWinner => determine_winner
+        status => status
+        getLotteryInfo => get_lottery_info
+        getTicketCount => get_ticket_count
+        getLotteryNames => lottery_names
+        getUserTicketCount => entries_for_user
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,65 @@
+use multiversx_sc::{api::ManagedTypeApi, types::*};
+
+multiversx_sc::derive_imports!();
+
+/// EGLD transfer to `to`, optionally executing `endpoint_name` there.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone)]
+pub struct CallActionData<M: ManagedTypeApi> {
+    pub to: ManagedAddress<M>,
+    pub egld_amount: BigUint<M>,
+    pub opt_gas_limit: Option<u64>,
+    pub endpoint_name: ManagedBuffer<M>,
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+/// ESDT transfer to `to`, optionally executing `endpoint_name` there.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone)]
+pub struct EsdtTransferExecuteData<M: ManagedTypeApi> {
+    pub to: ManagedAddress<M>,
+    pub tokens: ManagedVec<M, EsdtTokenPayment<M>>,
+    pub opt_gas_limit: Option<u64>,
+    pub endpoint_name: ManagedBuffer<M>,
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+/// Everything the board can vote on. A discarded or performed action is stored as `Nothing`.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone)]
+pub enum Action<M: ManagedTypeApi> {
+    Nothing,
+    AddBoardMember(ManagedAddress<M>),
+    RemoveBoardMember(ManagedAddress<M>),
+    ChangeQuorum(usize),
+    SendTransferExecuteEgld(CallActionData<M>),
+    SendTransferExecuteEsdt(EsdtTransferExecuteData<M>),
+    SCDeployFromSource {
+        amount: BigUint<M>,
+        source: ManagedAddress<M>,
+        code_metadata: CodeMetadata,
+        arguments: ManagedVec<M, ManagedBuffer<M>>,
+    },
+    SCUpgradeFromSource {
+        sc_address: ManagedAddress<M>,
+        amount: BigUint<M>,
+        source: ManagedAddress<M>,
+        code_metadata: CodeMetadata,
+        arguments: ManagedVec<M, ManagedBuffer<M>>,
+    },
+}
+
+impl<M: ManagedTypeApi> Action<M> {
+    pub fn is_pending(&self) -> bool {
+        !matches!(*self, Action::Nothing)
+    }
+}
+
+/// A pending action together with everyone who signed it, returned by `getPendingActionFullInfo`.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct ActionFullInfo<M: ManagedTypeApi> {
+    pub action_id: usize,
+    pub action_data: Action<M>,
+    pub signers: ManagedVec<M, ManagedAddress<M>>,
+}
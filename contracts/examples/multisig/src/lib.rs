@@ -0,0 +1,144 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod action;
+pub mod multisig_perform;
+pub mod multisig_propose;
+pub mod multisig_proxy;
+pub mod multisig_state;
+
+use action::{Action, ActionFullInfo};
+
+pub const ERR_NOT_BOARD_MEMBER: &str = "only board members can sign, propose and perform";
+pub const ERR_ACTION_NOT_FOUND: &str = "action does not exist";
+pub const ERR_QUORUM_NOT_REACHED: &str = "quorum has not been reached";
+pub const ERR_INVALID_QUORUM: &str = "quorum must be between 1 and the board size";
+pub const ERR_NO_EFFECT: &str = "proposed action has no effect";
+pub const ERR_DISCARD_SIGNED: &str = "cannot discard an action with valid signatures";
+
+/// M-of-N multisig: board members propose actions, sign them, and any board member
+/// can perform an action once `quorum` current board members have signed it.
+#[multiversx_sc::contract]
+pub trait Multisig:
+    multisig_state::MultisigStateModule
+    + multisig_propose::MultisigProposeModule
+    + multisig_perform::MultisigPerformModule
+{
+    #[init]
+    fn init(&self, quorum: usize, board: MultiValueEncoded<ManagedAddress>) {
+        for board_member in board {
+            require!(
+                self.board_members().insert(board_member),
+                "duplicate board member"
+            );
+        }
+        require!(
+            quorum > 0 && quorum <= self.board_members().len(),
+            ERR_INVALID_QUORUM
+        );
+        self.quorum().set(quorum);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Funds the multisig with EGLD or ESDTs.
+    #[payable("*")]
+    #[endpoint]
+    fn deposit(&self) {}
+
+    #[endpoint]
+    fn sign(&self, action_id: usize) {
+        let caller = self.blockchain().get_caller();
+        self.require_board_member(&caller);
+        self.require_pending_action(action_id);
+
+        self.action_signers(action_id).insert(caller);
+    }
+
+    #[endpoint]
+    fn unsign(&self, action_id: usize) {
+        let caller = self.blockchain().get_caller();
+        self.require_board_member(&caller);
+        self.require_pending_action(action_id);
+
+        self.action_signers(action_id).swap_remove(&caller);
+    }
+
+    /// Drops a pending action. Every current board member must have unsigned it first.
+    #[endpoint(discardAction)]
+    fn discard_action(&self, action_id: usize) {
+        let caller = self.blockchain().get_caller();
+        self.require_board_member(&caller);
+        self.require_pending_action(action_id);
+        require!(self.valid_signer_count(action_id) == 0, ERR_DISCARD_SIGNED);
+
+        self.clear_action(action_id);
+    }
+
+    #[view(getPendingActionFullInfo)]
+    fn get_pending_action_full_info(&self) -> MultiValueEncoded<ActionFullInfo<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for (action_id, action_data) in self.action_mapper().iter().enumerate() {
+            let action_id = action_id + 1;
+            if action_data.is_pending() {
+                result.push(ActionFullInfo {
+                    action_id,
+                    action_data,
+                    signers: self.get_action_signers(action_id),
+                });
+            }
+        }
+        result
+    }
+
+    /// `Nothing` for performed, discarded or unknown actions.
+    #[view(getActionData)]
+    fn get_action_data(&self, action_id: usize) -> Action<Self::Api> {
+        if action_id == 0 || action_id > self.action_mapper().len() {
+            return Action::Nothing;
+        }
+        self.action_mapper().get(action_id)
+    }
+
+    #[view(getActionSigners)]
+    fn get_action_signers(&self, action_id: usize) -> ManagedVec<ManagedAddress> {
+        self.action_signers(action_id).iter().collect()
+    }
+
+    #[view(getActionSignerCount)]
+    fn get_action_signer_count(&self, action_id: usize) -> usize {
+        self.action_signers(action_id).len()
+    }
+
+    #[view(getActionValidSignerCount)]
+    fn get_action_valid_signer_count(&self, action_id: usize) -> usize {
+        self.valid_signer_count(action_id)
+    }
+
+    #[view(quorumReached)]
+    fn quorum_reached(&self, action_id: usize) -> bool {
+        self.valid_signer_count(action_id) >= self.quorum().get()
+    }
+
+    #[view(getActionLastIndex)]
+    fn get_action_last_index(&self) -> usize {
+        self.action_mapper().len()
+    }
+
+    #[view(getNumBoardMembers)]
+    fn get_num_board_members(&self) -> usize {
+        self.board_members().len()
+    }
+
+    #[view(getAllBoardMembers)]
+    fn get_all_board_members(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.board_members().iter().collect()
+    }
+
+    #[view(isBoardMember)]
+    fn is_board_member(&self, address: ManagedAddress) -> bool {
+        self.board_members().contains(&address)
+    }
+}
@@ -0,0 +1,128 @@
+multiversx_sc::imports!();
+
+use crate::action::Action;
+use crate::{ERR_INVALID_QUORUM, ERR_QUORUM_NOT_REACHED};
+
+/// Gas kept back for finishing `performAction` after a transfer-execute.
+const PERFORM_ACTION_FINISH_GAS: u64 = 300_000;
+
+#[multiversx_sc::module]
+pub trait MultisigPerformModule: crate::multisig_state::MultisigStateModule {
+    /// Performs an action that reached quorum. Returns the new address for SC deploys.
+    #[endpoint(performAction)]
+    fn perform_action_endpoint(&self, action_id: usize) -> OptionalValue<ManagedAddress> {
+        let caller = self.blockchain().get_caller();
+        self.require_board_member(&caller);
+        self.require_pending_action(action_id);
+        require!(
+            self.valid_signer_count(action_id) >= self.quorum().get(),
+            ERR_QUORUM_NOT_REACHED
+        );
+
+        self.perform_action(action_id)
+    }
+
+    fn perform_action(&self, action_id: usize) -> OptionalValue<ManagedAddress> {
+        let action = self.action_mapper().get(action_id);
+        // cleared before any outgoing call, so the action cannot be performed twice
+        self.clear_action(action_id);
+
+        match action {
+            Action::Nothing => OptionalValue::None,
+            Action::AddBoardMember(board_member_address) => {
+                self.board_members().insert(board_member_address);
+                OptionalValue::None
+            }
+            Action::RemoveBoardMember(board_member_address) => {
+                self.board_members().swap_remove(&board_member_address);
+                self.require_quorum_fits_board(self.quorum().get());
+                OptionalValue::None
+            }
+            Action::ChangeQuorum(new_quorum) => {
+                self.require_quorum_fits_board(new_quorum);
+                self.quorum().set(new_quorum);
+                OptionalValue::None
+            }
+            Action::SendTransferExecuteEgld(call_data) => {
+                let gas = call_data
+                    .opt_gas_limit
+                    .unwrap_or_else(|| self.gas_for_transfer_execute());
+                self.tx()
+                    .to(call_data.to)
+                    .egld(call_data.egld_amount)
+                    .gas(gas)
+                    .raw_call(call_data.endpoint_name)
+                    .arguments_raw(call_data.arguments.into())
+                    .transfer_execute();
+                OptionalValue::None
+            }
+            Action::SendTransferExecuteEsdt(call_data) => {
+                let gas = call_data
+                    .opt_gas_limit
+                    .unwrap_or_else(|| self.gas_for_transfer_execute());
+                self.tx()
+                    .to(call_data.to)
+                    .payment(call_data.tokens)
+                    .gas(gas)
+                    .raw_call(call_data.endpoint_name)
+                    .arguments_raw(call_data.arguments.into())
+                    .transfer_execute();
+                OptionalValue::None
+            }
+            Action::SCDeployFromSource {
+                amount,
+                source,
+                code_metadata,
+                arguments,
+            } => {
+                let gas_left = self.blockchain().get_gas_left();
+                let new_address = self
+                    .tx()
+                    .egld(amount)
+                    .gas(gas_left)
+                    .raw_deploy()
+                    .from_source(source)
+                    .code_metadata(code_metadata)
+                    .arguments_raw(arguments.into())
+                    .returns(ReturnsNewManagedAddress)
+                    .sync_call();
+                OptionalValue::Some(new_address)
+            }
+            Action::SCUpgradeFromSource {
+                sc_address,
+                amount,
+                source,
+                code_metadata,
+                arguments,
+            } => {
+                let gas_left = self.blockchain().get_gas_left();
+                self.tx()
+                    .to(sc_address)
+                    .egld(amount)
+                    .gas(gas_left)
+                    .raw_upgrade()
+                    .from_source(source)
+                    .code_metadata(code_metadata)
+                    .arguments_raw(arguments.into())
+                    .upgrade_async_call_and_exit();
+                OptionalValue::None
+            }
+        }
+    }
+
+    fn require_quorum_fits_board(&self, quorum: usize) {
+        require!(
+            quorum > 0 && quorum <= self.board_members().len(),
+            ERR_INVALID_QUORUM
+        );
+    }
+
+    fn gas_for_transfer_execute(&self) -> u64 {
+        let gas_left = self.blockchain().get_gas_left();
+        require!(
+            gas_left > PERFORM_ACTION_FINISH_GAS,
+            "insufficient gas for call"
+        );
+        gas_left - PERFORM_ACTION_FINISH_GAS
+    }
+}
@@ -0,0 +1,113 @@
+multiversx_sc::imports!();
+
+use crate::action::{Action, CallActionData, EsdtTransferExecuteData};
+use crate::{ERR_INVALID_QUORUM, ERR_NO_EFFECT};
+
+/// One endpoint per action kind. The proposer's signature is added right away.
+#[multiversx_sc::module]
+pub trait MultisigProposeModule: crate::multisig_state::MultisigStateModule {
+    fn propose_action(&self, action: Action<Self::Api>) -> usize {
+        let caller = self.blockchain().get_caller();
+        self.require_board_member(&caller);
+
+        let action_id = self.action_mapper().push(&action);
+        self.action_signers(action_id).insert(caller);
+
+        action_id
+    }
+
+    #[endpoint(proposeAddBoardMember)]
+    fn propose_add_board_member(&self, board_member_address: ManagedAddress) -> usize {
+        require!(
+            !self.board_members().contains(&board_member_address),
+            "already a board member"
+        );
+        self.propose_action(Action::AddBoardMember(board_member_address))
+    }
+
+    #[endpoint(proposeRemoveBoardMember)]
+    fn propose_remove_board_member(&self, board_member_address: ManagedAddress) -> usize {
+        self.require_board_member(&board_member_address);
+        self.propose_action(Action::RemoveBoardMember(board_member_address))
+    }
+
+    #[endpoint(proposeChangeQuorum)]
+    fn propose_change_quorum(&self, new_quorum: usize) -> usize {
+        require!(new_quorum > 0, ERR_INVALID_QUORUM);
+        self.propose_action(Action::ChangeQuorum(new_quorum))
+    }
+
+    /// EGLD transfer, SC call, or both. `function_call` is the endpoint name followed by its arguments.
+    #[endpoint(proposeTransferExecute)]
+    fn propose_transfer_execute(
+        &self,
+        to: ManagedAddress,
+        egld_amount: BigUint,
+        opt_gas_limit: Option<u64>,
+        function_call: FunctionCall,
+    ) -> usize {
+        require!(egld_amount > 0 || !function_call.is_empty(), ERR_NO_EFFECT);
+        self.propose_action(Action::SendTransferExecuteEgld(CallActionData {
+            to,
+            egld_amount,
+            opt_gas_limit,
+            endpoint_name: function_call.function_name,
+            arguments: function_call.arg_buffer.into_vec_of_buffers(),
+        }))
+    }
+
+    /// ESDT transfer, optionally followed by an SC call on the receiver.
+    #[endpoint(proposeTransferExecuteEsdt)]
+    fn propose_transfer_execute_esdt(
+        &self,
+        to: ManagedAddress,
+        tokens: ManagedVec<EsdtTokenPayment>,
+        opt_gas_limit: Option<u64>,
+        function_call: FunctionCall,
+    ) -> usize {
+        require!(!tokens.is_empty(), ERR_NO_EFFECT);
+        self.propose_action(Action::SendTransferExecuteEsdt(EsdtTransferExecuteData {
+            to,
+            tokens,
+            opt_gas_limit,
+            endpoint_name: function_call.function_name,
+            arguments: function_call.arg_buffer.into_vec_of_buffers(),
+        }))
+    }
+
+    /// Deploys a copy of the code at `source`; the multisig becomes the owner of the new contract.
+    #[endpoint(proposeSCDeployFromSource)]
+    fn propose_sc_deploy_from_source(
+        &self,
+        amount: BigUint,
+        source: ManagedAddress,
+        code_metadata: CodeMetadata,
+        arguments: MultiValueEncoded<ManagedBuffer>,
+    ) -> usize {
+        self.propose_action(Action::SCDeployFromSource {
+            amount,
+            source,
+            code_metadata,
+            arguments: arguments.to_vec(),
+        })
+    }
+
+    /// Upgrades a contract owned by the multisig to the code at `source`.
+    #[endpoint(proposeSCUpgradeFromSource)]
+    fn propose_sc_upgrade_from_source(
+        &self,
+        sc_address: ManagedAddress,
+        amount: BigUint,
+        source: ManagedAddress,
+        code_metadata: CodeMetadata,
+        arguments: MultiValueEncoded<ManagedBuffer>,
+    ) -> usize {
+        self.propose_action(Action::SCUpgradeFromSource {
+            sc_address,
+            amount,
+            source,
+            code_metadata,
+            arguments: arguments.to_vec(),
+        })
+    }
+}
@@ -0,0 +1,476 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct MultisigProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for MultisigProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = MultisigProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        MultisigProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct MultisigProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> MultisigProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        quorum: Arg0,
+        board: Arg1,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&quorum)
+            .argument(&board)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> MultisigProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> MultisigProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Funds the multisig with EGLD or ESDTs. 
+    pub fn deposit(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit")
+            .original_result()
+    }
+
+    pub fn sign<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("sign")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn unsign<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unsign")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    /// Drops a pending action. Every current board member must have unsigned it first. 
+    pub fn discard_action<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("discardAction")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn get_pending_action_full_info(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ActionFullInfo<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingActionFullInfo")
+            .original_result()
+    }
+
+    /// `Nothing` for performed, discarded or unknown actions. 
+    pub fn get_action_data<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Action<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActionData")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn get_action_signers<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActionSigners")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn get_action_signer_count<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActionSignerCount")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn get_action_valid_signer_count<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActionValidSignerCount")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn quorum_reached<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("quorumReached")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn get_action_last_index(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActionLastIndex")
+            .original_result()
+    }
+
+    pub fn get_num_board_members(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNumBoardMembers")
+            .original_result()
+    }
+
+    pub fn get_all_board_members(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllBoardMembers")
+            .original_result()
+    }
+
+    pub fn is_board_member<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isBoardMember")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn quorum(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuorum")
+            .original_result()
+    }
+
+    pub fn propose_add_board_member<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        board_member_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeAddBoardMember")
+            .argument(&board_member_address)
+            .original_result()
+    }
+
+    pub fn propose_remove_board_member<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        board_member_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeRemoveBoardMember")
+            .argument(&board_member_address)
+            .original_result()
+    }
+
+    pub fn propose_change_quorum<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        new_quorum: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeChangeQuorum")
+            .argument(&new_quorum)
+            .original_result()
+    }
+
+    /// EGLD transfer, SC call, or both. `function_call` is the endpoint name followed by its arguments. 
+    pub fn propose_transfer_execute<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<Option<u64>>,
+        Arg3: ProxyArg<FunctionCall<Env::Api>>,
+    >(
+        self,
+        to: Arg0,
+        egld_amount: Arg1,
+        opt_gas_limit: Arg2,
+        function_call: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeTransferExecute")
+            .argument(&to)
+            .argument(&egld_amount)
+            .argument(&opt_gas_limit)
+            .argument(&function_call)
+            .original_result()
+    }
+
+    /// ESDT transfer, optionally followed by an SC call on the receiver. 
+    pub fn propose_transfer_execute_esdt<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>>,
+        Arg2: ProxyArg<Option<u64>>,
+        Arg3: ProxyArg<FunctionCall<Env::Api>>,
+    >(
+        self,
+        to: Arg0,
+        tokens: Arg1,
+        opt_gas_limit: Arg2,
+        function_call: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeTransferExecuteEsdt")
+            .argument(&to)
+            .argument(&tokens)
+            .argument(&opt_gas_limit)
+            .argument(&function_call)
+            .original_result()
+    }
+
+    /// Deploys a copy of the code at `source`; the multisig becomes the owner of the new contract. 
+    pub fn propose_sc_deploy_from_source<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<CodeMetadata>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        amount: Arg0,
+        source: Arg1,
+        code_metadata: Arg2,
+        arguments: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeSCDeployFromSource")
+            .argument(&amount)
+            .argument(&source)
+            .argument(&code_metadata)
+            .argument(&arguments)
+            .original_result()
+    }
+
+    /// Upgrades a contract owned by the multisig to the code at `source`. 
+    pub fn propose_sc_upgrade_from_source<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<CodeMetadata>,
+        Arg4: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        sc_address: Arg0,
+        amount: Arg1,
+        source: Arg2,
+        code_metadata: Arg3,
+        arguments: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeSCUpgradeFromSource")
+            .argument(&sc_address)
+            .argument(&amount)
+            .argument(&source)
+            .argument(&code_metadata)
+            .argument(&arguments)
+            .original_result()
+    }
+
+    /// Performs an action that reached quorum. Returns the new address for SC deploys. 
+    pub fn perform_action_endpoint<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("performAction")
+            .argument(&action_id)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct ActionFullInfo<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action_id: usize,
+    pub action_data: Action<Api>,
+    pub signers: ManagedVec<Api, ManagedAddress<Api>>,
+}
+
+#[rustfmt::skip]
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone)]
+pub enum Action<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Nothing,
+    AddBoardMember(ManagedAddress<Api>),
+    RemoveBoardMember(ManagedAddress<Api>),
+    ChangeQuorum(usize),
+    SendTransferExecuteEgld(CallActionData<Api>),
+    SendTransferExecuteEsdt(EsdtTransferExecuteData<Api>),
+    SCDeployFromSource {
+        amount: BigUint<Api>,
+        source: ManagedAddress<Api>,
+        code_metadata: CodeMetadata,
+        arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+    },
+    SCUpgradeFromSource {
+        sc_address: ManagedAddress<Api>,
+        amount: BigUint<Api>,
+        source: ManagedAddress<Api>,
+        code_metadata: CodeMetadata,
+        arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+    },
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone)]
+pub struct CallActionData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub to: ManagedAddress<Api>,
+    pub egld_amount: BigUint<Api>,
+    pub opt_gas_limit: Option<u64>,
+    pub endpoint_name: ManagedBuffer<Api>,
+    pub arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone)]
+pub struct EsdtTransferExecuteData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub to: ManagedAddress<Api>,
+    pub tokens: ManagedVec<Api, EsdtTokenPayment<Api>>,
+    pub opt_gas_limit: Option<u64>,
+    pub endpoint_name: ManagedBuffer<Api>,
+    pub arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+}
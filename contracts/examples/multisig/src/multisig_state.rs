@@ -0,0 +1,48 @@
+multiversx_sc::imports!();
+
+use crate::action::Action;
+use crate::{ERR_ACTION_NOT_FOUND, ERR_NOT_BOARD_MEMBER};
+
+/// Board, quorum and action storage, shared by the other multisig modules.
+#[multiversx_sc::module]
+pub trait MultisigStateModule {
+    fn require_board_member(&self, address: &ManagedAddress) {
+        require!(self.board_members().contains(address), ERR_NOT_BOARD_MEMBER);
+    }
+
+    fn require_pending_action(&self, action_id: usize) {
+        require!(
+            action_id >= 1
+                && action_id <= self.action_mapper().len()
+                && self.action_mapper().get(action_id).is_pending(),
+            ERR_ACTION_NOT_FOUND
+        );
+    }
+
+    /// Signatures of addresses that were removed from the board since signing do not count.
+    fn valid_signer_count(&self, action_id: usize) -> usize {
+        let board_members = self.board_members();
+        self.action_signers(action_id)
+            .iter()
+            .filter(|signer| board_members.contains(signer))
+            .count()
+    }
+
+    fn clear_action(&self, action_id: usize) {
+        self.action_mapper().clear_entry(action_id);
+        self.action_signers(action_id).clear();
+    }
+
+    #[view(getQuorum)]
+    #[storage_mapper("quorum")]
+    fn quorum(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("boardMembers")]
+    fn board_members(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("actionData")]
+    fn action_mapper(&self) -> VecMapper<Action<Self::Api>>;
+
+    #[storage_mapper("actionSigners")]
+    fn action_signers(&self, action_id: usize) -> UnorderedSetMapper<ManagedAddress>;
+}
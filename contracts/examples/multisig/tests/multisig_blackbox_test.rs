@@ -0,0 +1,178 @@
+use multiversx_sc_scenario::imports::*;
+
+use multisig::multisig_proxy::{self, Action};
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const ALICE_ADDRESS: TestAddress = TestAddress::new("alice");
+const BOB_ADDRESS: TestAddress = TestAddress::new("bob");
+const CAROL_ADDRESS: TestAddress = TestAddress::new("carol");
+const DAVE_ADDRESS: TestAddress = TestAddress::new("dave");
+const MULTISIG_ADDRESS: TestSCAddress = TestSCAddress::new("multisig");
+const CODE_PATH: MxscPath = MxscPath::new("output/multisig.mxsc.json");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/multisig");
+    blockchain.register_contract(CODE_PATH, multisig::ContractBuilder);
+    blockchain
+}
+
+struct MultisigTestState {
+    world: ScenarioWorld,
+}
+
+impl MultisigTestState {
+    /// Deploys a 2-of-3 multisig with alice, bob and carol on the board.
+    fn new() -> Self {
+        let mut world = world();
+
+        world.account(OWNER_ADDRESS).nonce(1);
+        for member in [ALICE_ADDRESS, BOB_ADDRESS, CAROL_ADDRESS, DAVE_ADDRESS] {
+            world.account(member).nonce(1);
+        }
+
+        let mut board = MultiValueEncoded::new();
+        for member in [ALICE_ADDRESS, BOB_ADDRESS, CAROL_ADDRESS] {
+            board.push(member.to_managed_address());
+        }
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .init(2usize, board)
+            .code(CODE_PATH)
+            .new_address(MULTISIG_ADDRESS)
+            .run();
+
+        Self { world }
+    }
+
+    fn propose_add_board_member(&mut self, proposer: TestAddress, member: TestAddress) -> usize {
+        self.world
+            .tx()
+            .from(proposer)
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .propose_add_board_member(member)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn propose_remove_board_member(&mut self, proposer: TestAddress, member: TestAddress) -> usize {
+        self.world
+            .tx()
+            .from(proposer)
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .propose_remove_board_member(member)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn propose_change_quorum(&mut self, proposer: TestAddress, quorum: usize) -> usize {
+        self.world
+            .tx()
+            .from(proposer)
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .propose_change_quorum(quorum)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn sign(&mut self, signer: TestAddress, action_id: usize) {
+        self.world
+            .tx()
+            .from(signer)
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .sign(action_id)
+            .run();
+    }
+
+    fn perform(&mut self, performer: TestAddress, action_id: usize) {
+        self.world
+            .tx()
+            .from(performer)
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .perform_action_endpoint(action_id)
+            .run();
+    }
+
+    /// `(action id, action, signer count)` of every pending action, in id order.
+    fn pending_actions(&mut self) -> Vec<(usize, Action<StaticApi>, usize)> {
+        self.world
+            .query()
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .get_pending_action_full_info()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|info| (info.action_id, info.action_data, info.signers.len()))
+            .collect()
+    }
+
+    fn quorum_reached(&mut self, action_id: usize) -> bool {
+        self.world
+            .query()
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .quorum_reached(action_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+}
+
+#[test]
+fn multisig_blackbox_pending_actions_view() {
+    let mut state = MultisigTestState::new();
+    assert!(state.pending_actions().is_empty());
+
+    let add_dave = state.propose_add_board_member(ALICE_ADDRESS, DAVE_ADDRESS);
+    let change_quorum = state.propose_change_quorum(BOB_ADDRESS, 3);
+    assert_eq!((add_dave, change_quorum), (1, 2));
+
+    let pending = state.pending_actions();
+    assert_eq!(pending.len(), 2);
+    assert!(
+        matches!(&pending[0], (1, Action::AddBoardMember(member), 1) if *member == DAVE_ADDRESS.to_managed_address())
+    );
+    assert!(matches!(&pending[1], (2, Action::ChangeQuorum(3), 1)));
+    assert!(!state.quorum_reached(add_dave));
+
+    state.sign(CAROL_ADDRESS, add_dave);
+    assert!(state.quorum_reached(add_dave));
+    state.perform(CAROL_ADDRESS, add_dave);
+
+    let pending = state.pending_actions();
+    assert_eq!(pending.len(), 1);
+    assert!(matches!(&pending[0], (2, Action::ChangeQuorum(3), 1)));
+}
+
+#[test]
+fn multisig_blackbox_removed_member_signature_stops_counting() {
+    let mut state = MultisigTestState::new();
+
+    let remove_carol = state.propose_remove_board_member(ALICE_ADDRESS, CAROL_ADDRESS);
+    let change_quorum = state.propose_change_quorum(CAROL_ADDRESS, 1);
+    state.sign(BOB_ADDRESS, remove_carol);
+    assert!(!state.quorum_reached(change_quorum));
+    state.sign(ALICE_ADDRESS, change_quorum);
+    assert!(state.quorum_reached(change_quorum));
+
+    state.perform(BOB_ADDRESS, remove_carol);
+
+    // carol's signature no longer counts once she has left the board
+    assert!(!state.quorum_reached(change_quorum));
+    state
+        .world
+        .tx()
+        .from(ALICE_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .perform_action_endpoint(change_quorum)
+        .with_result(ExpectError(4, "quorum has not been reached"))
+        .run();
+}
@@ -0,0 +1,32 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/multisig");
+    blockchain.register_contract("mxsc:output/multisig.mxsc.json", multisig::ContractBuilder);
+    blockchain.register_contract(
+        "mxsc:../adder/output/adder.mxsc.json",
+        adder::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn multisig_board_rs() {
+    world().run("scenarios/multisig-board.scen.json");
+}
+
+#[test]
+fn multisig_deploy_upgrade_adder_rs() {
+    world().run("scenarios/multisig-deploy-upgrade-adder.scen.json");
+}
+
+#[test]
+fn multisig_init_invalid_rs() {
+    world().run("scenarios/multisig-init-invalid.scen.json");
+}
+
+#[test]
+fn multisig_transfer_rs() {
+    world().run("scenarios/multisig-transfer.scen.json");
+}
@@ -0,0 +1,49 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           23
+// Async Callback (empty):               1
+// Total number of exported functions:  26
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    multisig
+    (
+        init => init
+        upgrade => upgrade
+        deposit => deposit
+        sign => sign
+        unsign => unsign
+        discardAction => discard_action
+        getPendingActionFullInfo => get_pending_action_full_info
+        getActionData => get_action_data
+        getActionSigners => get_action_signers
+        getActionSignerCount => get_action_signer_count
+        getActionValidSignerCount => get_action_valid_signer_count
+        quorumReached => quorum_reached
+        getActionLastIndex => get_action_last_index
+        getNumBoardMembers => get_num_board_members
+        getAllBoardMembers => get_all_board_members
+        isBoardMember => is_board_member
+        getQuorum => quorum
+        proposeAddBoardMember => propose_add_board_member
+        proposeRemoveBoardMember => propose_remove_board_member
+        proposeChangeQuorum => propose_change_quorum
+        proposeTransferExecute => propose_transfer_execute
+        proposeTransferExecuteEsdt => propose_transfer_execute_esdt
+        proposeSCDeployFromSource => propose_sc_deploy_from_source
+        proposeSCUpgradeFromSource => propose_sc_upgrade_from_source
+        performAction => perform_action_endpoint
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,288 @@
+#![no_std]
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub mod nft_minter_proxy;
+
+pub const ERR_ROYALTIES_TOO_HIGH: &str = "royalties cannot exceed 10000";
+pub const ERR_MINT_NOT_CONFIGURED: &str = "paid mint not configured";
+pub const ERR_WRONG_PAYMENT: &str = "payment must match the mint price";
+pub const ERR_WALLET_LIMIT: &str = "mint limit per wallet reached";
+pub const ERR_NOTHING_TO_WITHDRAW: &str = "nothing to withdraw";
+pub const ERR_NOT_SEMI_FUNGIBLE: &str = "collection is not semi-fungible";
+pub const ERR_ZERO_AMOUNT: &str = "amount cannot be zero";
+
+/// 100% in ESDT royalties units.
+pub const MAX_ROYALTIES: u64 = 10_000;
+
+/// On-chain attributes of every token in the collection, `TopEncode`d into the token.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct NftAttributes<M: ManagedTypeApi> {
+    /// 0 for owner mints; paid mints are numbered from 1.
+    pub edition: u64,
+    pub metadata: ManagedBuffer<M>,
+    pub tags: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+/// What every paid mint creates; only the attributes edition differs between copies.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct MintTemplate<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,
+    pub royalties: BigUint<M>,
+    pub attributes: NftAttributes<M>,
+    pub uris: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+/// Issues an NFT or SFT collection and mints into it, either directly by the owner
+/// or by anyone paying the mint price, up to a limit per wallet.
+#[multiversx_sc::contract]
+pub trait NftMinter {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Issues the collection and grants this contract the NFT create role in one system SC call.
+    /// The EGLD payment is the issue cost; it is returned to the caller if the issue fails.
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueCollection)]
+    fn issue_collection(&self, collection_name: ManagedBuffer, ticker: ManagedBuffer) {
+        self.issue(EsdtTokenType::NonFungible, collection_name, ticker);
+    }
+
+    /// Same as `issueCollection`, for a semi-fungible collection minted with `createSft`.
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueSftCollection)]
+    fn issue_sft_collection(&self, collection_name: ManagedBuffer, ticker: ManagedBuffer) {
+        self.issue(EsdtTokenType::SemiFungible, collection_name, ticker);
+    }
+
+    fn issue(
+        &self,
+        token_type: EsdtTokenType,
+        collection_name: ManagedBuffer,
+        ticker: ManagedBuffer,
+    ) {
+        let issue_cost = self.call_value().egld().clone_value();
+        let caller = self.blockchain().get_caller();
+        let semi_fungible = token_type == EsdtTokenType::SemiFungible;
+        self.nft_token().issue_and_set_all_roles(
+            token_type,
+            issue_cost,
+            collection_name,
+            ticker,
+            0,
+            Some(self.callbacks().issue_callback(&caller, semi_fungible)),
+        );
+    }
+
+    #[callback]
+    fn issue_callback(
+        &self,
+        caller: &ManagedAddress,
+        semi_fungible: bool,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.nft_token().set_token_id(token_id);
+                self.semi_fungible().set(semi_fungible);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.nft_token().clear();
+                let returned = self.call_value().egld().clone_value();
+                self.tx().to(caller).egld(returned).transfer_if_not_empty();
+            }
+        }
+    }
+
+    /// Mints one NFT and sends it to `receiver`. Returns the new nonce.
+    #[only_owner]
+    #[endpoint(createNft)]
+    fn create_nft(
+        &self,
+        receiver: ManagedAddress,
+        name: ManagedBuffer,
+        royalties: BigUint,
+        attributes: NftAttributes<Self::Api>,
+        uris: MultiValueEncoded<ManagedBuffer>,
+    ) -> u64 {
+        self.create_and_send(
+            &receiver,
+            &BigUint::from(1u32),
+            &name,
+            &royalties,
+            &attributes,
+            &uris.to_vec(),
+        )
+    }
+
+    /// Mints `amount` copies of one SFT and sends them to `receiver`. Returns the new nonce.
+    #[only_owner]
+    #[endpoint(createSft)]
+    fn create_sft(
+        &self,
+        receiver: ManagedAddress,
+        amount: BigUint,
+        name: ManagedBuffer,
+        royalties: BigUint,
+        attributes: NftAttributes<Self::Api>,
+        uris: MultiValueEncoded<ManagedBuffer>,
+    ) -> u64 {
+        require!(self.semi_fungible().get(), ERR_NOT_SEMI_FUNGIBLE);
+        require!(amount > 0, ERR_ZERO_AMOUNT);
+
+        self.create_and_send(
+            &receiver,
+            &amount,
+            &name,
+            &royalties,
+            &attributes,
+            &uris.to_vec(),
+        )
+    }
+
+    /// Sets what paid mints create and what they cost.
+    /// `max_per_wallet` of 0 means no limit.
+    #[only_owner]
+    #[endpoint(setMintConfig)]
+    fn set_mint_config(
+        &self,
+        template: MintTemplate<Self::Api>,
+        price_token: EgldOrEsdtTokenIdentifier,
+        price: BigUint,
+        max_per_wallet: u32,
+    ) {
+        require!(template.royalties <= MAX_ROYALTIES, ERR_ROYALTIES_TOO_HIGH);
+        require!(price_token.is_valid(), "invalid price token");
+
+        self.mint_template().set(template);
+        self.mint_price_token().set(price_token);
+        self.mint_price().set(price);
+        self.max_per_wallet().set(max_per_wallet);
+    }
+
+    /// Mints one NFT from the template for the caller, against exactly the mint price.
+    /// Returns the new nonce.
+    #[payable("*")]
+    #[endpoint]
+    fn mint(&self) -> u64 {
+        require!(!self.mint_template().is_empty(), ERR_MINT_NOT_CONFIGURED);
+
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(
+            payment.token_identifier == self.mint_price_token().get()
+                && payment.amount == self.mint_price().get(),
+            ERR_WRONG_PAYMENT
+        );
+
+        let caller = self.blockchain().get_caller();
+        let max_per_wallet = self.max_per_wallet().get();
+        let minted_count = self.minted_count(&caller).get();
+        require!(
+            max_per_wallet == 0 || minted_count < max_per_wallet,
+            ERR_WALLET_LIMIT
+        );
+        self.minted_count(&caller).set(minted_count + 1);
+
+        let edition = self.paid_mint_count().update(|count| {
+            *count += 1;
+            *count
+        });
+        let mut template = self.mint_template().get();
+        template.attributes.edition = edition;
+
+        self.create_and_send(
+            &caller,
+            &BigUint::from(1u32),
+            &template.name,
+            &template.royalties,
+            &template.attributes,
+            &template.uris,
+        )
+    }
+
+    /// Sends the contract's whole balance of `token` to the owner.
+    /// Not tied to the current price token, so proceeds from an earlier config stay reachable.
+    #[only_owner]
+    #[endpoint]
+    fn withdraw(&self, token: EgldOrEsdtTokenIdentifier, opt_nonce: OptionalValue<u64>) {
+        let nonce = opt_nonce.into_option().unwrap_or_default();
+        let balance = self.blockchain().get_sc_balance(&token, nonce);
+        require!(balance > 0, ERR_NOTHING_TO_WITHDRAW);
+
+        let owner = self.blockchain().get_owner_address();
+        self.tx()
+            .to(&owner)
+            .egld_or_single_esdt(&token, nonce, &balance)
+            .transfer();
+    }
+
+    fn create_and_send(
+        &self,
+        receiver: &ManagedAddress,
+        amount: &BigUint,
+        name: &ManagedBuffer,
+        royalties: &BigUint,
+        attributes: &NftAttributes<Self::Api>,
+        uris: &ManagedVec<ManagedBuffer>,
+    ) -> u64 {
+        require!(*royalties <= MAX_ROYALTIES, ERR_ROYALTIES_TOO_HIGH);
+
+        let token_id = self.nft_token().get_token_id();
+        let nonce = self.send().esdt_nft_create(
+            &token_id,
+            amount,
+            name,
+            royalties,
+            &ManagedBuffer::new(),
+            attributes,
+            uris,
+        );
+        self.tx()
+            .to(receiver)
+            .single_esdt(&token_id, nonce, amount)
+            .transfer();
+
+        nonce
+    }
+
+    #[view(getNftTokenId)]
+    #[storage_mapper("nftToken")]
+    fn nft_token(&self) -> NonFungibleTokenMapper;
+
+    /// Set when the collection was issued with `issueSftCollection`.
+    #[view(isSemiFungible)]
+    #[storage_mapper("semiFungible")]
+    fn semi_fungible(&self) -> SingleValueMapper<bool>;
+
+    #[view(getMintTemplate)]
+    #[storage_mapper("mintTemplate")]
+    fn mint_template(&self) -> SingleValueMapper<MintTemplate<Self::Api>>;
+
+    #[view(getMintPriceToken)]
+    #[storage_mapper("mintPriceToken")]
+    fn mint_price_token(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getMintPrice)]
+    #[storage_mapper("mintPrice")]
+    fn mint_price(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getMaxPerWallet)]
+    #[storage_mapper("maxPerWallet")]
+    fn max_per_wallet(&self) -> SingleValueMapper<u32>;
+
+    #[view(getMintedCount)]
+    #[storage_mapper("mintedCount")]
+    fn minted_count(&self, address: &ManagedAddress) -> SingleValueMapper<u32>;
+
+    #[view(getPaidMintCount)]
+    #[storage_mapper("paidMintCount")]
+    fn paid_mint_count(&self) -> SingleValueMapper<u64>;
+}
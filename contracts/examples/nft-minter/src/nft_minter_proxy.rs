@@ -0,0 +1,323 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct NftMinterProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for NftMinterProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = NftMinterProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        NftMinterProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct NftMinterProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> NftMinterProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init(
+        self,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> NftMinterProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> NftMinterProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Issues the collection and grants this contract the NFT create role in one system SC call. 
+    /// The EGLD payment is the issue cost; it is returned to the caller if the issue fails. 
+    pub fn issue_collection<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        collection_name: Arg0,
+        ticker: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("issueCollection")
+            .argument(&collection_name)
+            .argument(&ticker)
+            .original_result()
+    }
+
+    /// Same as `issueCollection`, for a semi-fungible collection minted with `createSft`. 
+    pub fn issue_sft_collection<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        collection_name: Arg0,
+        ticker: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("issueSftCollection")
+            .argument(&collection_name)
+            .argument(&ticker)
+            .original_result()
+    }
+
+    /// Mints one NFT and sends it to `receiver`. Returns the new nonce. 
+    pub fn create_nft<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<NftAttributes<Env::Api>>,
+        Arg4: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        receiver: Arg0,
+        name: Arg1,
+        royalties: Arg2,
+        attributes: Arg3,
+        uris: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createNft")
+            .argument(&receiver)
+            .argument(&name)
+            .argument(&royalties)
+            .argument(&attributes)
+            .argument(&uris)
+            .original_result()
+    }
+
+    /// Mints `amount` copies of one SFT and sends them to `receiver`. Returns the new nonce. 
+    pub fn create_sft<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<NftAttributes<Env::Api>>,
+        Arg5: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        receiver: Arg0,
+        amount: Arg1,
+        name: Arg2,
+        royalties: Arg3,
+        attributes: Arg4,
+        uris: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createSft")
+            .argument(&receiver)
+            .argument(&amount)
+            .argument(&name)
+            .argument(&royalties)
+            .argument(&attributes)
+            .argument(&uris)
+            .original_result()
+    }
+
+    /// Sets what paid mints create and what they cost. 
+    /// `max_per_wallet` of 0 means no limit. 
+    pub fn set_mint_config<
+        Arg0: ProxyArg<MintTemplate<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u32>,
+    >(
+        self,
+        template: Arg0,
+        price_token: Arg1,
+        price: Arg2,
+        max_per_wallet: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMintConfig")
+            .argument(&template)
+            .argument(&price_token)
+            .argument(&price)
+            .argument(&max_per_wallet)
+            .original_result()
+    }
+
+    /// Mints one NFT from the template for the caller, against exactly the mint price. 
+    /// Returns the new nonce. 
+    pub fn mint(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("mint")
+            .original_result()
+    }
+
+    /// Sends the contract's whole balance of `token` to the owner. 
+    /// Not tied to the current price token, so proceeds from an earlier config stay reachable. 
+    pub fn withdraw<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        token: Arg0,
+        opt_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdraw")
+            .argument(&token)
+            .argument(&opt_nonce)
+            .original_result()
+    }
+
+    pub fn nft_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNftTokenId")
+            .original_result()
+    }
+
+    /// Set when the collection was issued with `issueSftCollection`. 
+    pub fn semi_fungible(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isSemiFungible")
+            .original_result()
+    }
+
+    pub fn mint_template(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MintTemplate<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintTemplate")
+            .original_result()
+    }
+
+    pub fn mint_price_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintPriceToken")
+            .original_result()
+    }
+
+    pub fn mint_price(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintPrice")
+            .original_result()
+    }
+
+    pub fn max_per_wallet(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxPerWallet")
+            .original_result()
+    }
+
+    pub fn minted_count<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintedCount")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn paid_mint_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPaidMintCount")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct NftAttributes<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub edition: u64,
+    pub metadata: ManagedBuffer<Api>,
+    pub tags: ManagedVec<Api, ManagedBuffer<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct MintTemplate<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub name: ManagedBuffer<Api>,
+    pub royalties: BigUint<Api>,
+    pub attributes: NftAttributes<Api>,
+    pub uris: ManagedVec<Api, ManagedBuffer<Api>>,
+}
@@ -0,0 +1,260 @@
+use multiversx_sc_scenario::imports::*;
+
+use nft_minter::{nft_minter_proxy, NftAttributes, NftMinter};
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const USER_ADDRESS: TestAddress = TestAddress::new("user");
+const NFT_MINTER_ADDRESS: TestSCAddress = TestSCAddress::new("nft-minter");
+const NFT_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("NFT-123456");
+const CODE_PATH: MxscPath = MxscPath::new("output/nft-minter.mxsc.json");
+const MINT_PRICE: u64 = 1_000;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/nft-minter");
+    blockchain.register_contract(CODE_PATH, nft_minter::ContractBuilder);
+    blockchain
+}
+
+const METADATA: &str = "ipfs://meta/item.json";
+
+struct ExpectedAttributes {
+    edition: u64,
+    tags: &'static [&'static str],
+}
+
+impl ExpectedAttributes {
+    fn to_proxy_attributes(&self) -> nft_minter_proxy::NftAttributes<StaticApi> {
+        let mut tags = ManagedVec::new();
+        for tag in self.tags {
+            tags.push(ManagedBuffer::from(*tag));
+        }
+        nft_minter_proxy::NftAttributes {
+            edition: self.edition,
+            metadata: ManagedBuffer::from(METADATA),
+            tags,
+        }
+    }
+}
+
+struct NftMinterTestState {
+    world: ScenarioWorld,
+}
+
+impl NftMinterTestState {
+    /// Deploys and sets the collection as if `issueCollection` had gone through.
+    fn new() -> Self {
+        let mut world = world();
+
+        world.account(OWNER_ADDRESS).nonce(1);
+        world
+            .account(USER_ADDRESS)
+            .nonce(1)
+            .balance(10 * MINT_PRICE);
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(nft_minter_proxy::NftMinterProxy)
+            .init()
+            .code(CODE_PATH)
+            .new_address(NFT_MINTER_ADDRESS)
+            .run();
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(NFT_MINTER_ADDRESS)
+            .whitebox(nft_minter::contract_obj, |sc| {
+                sc.nft_token()
+                    .set_token_id(NFT_TOKEN_ID.to_token_identifier());
+            });
+        world.set_esdt_local_roles(
+            NFT_MINTER_ADDRESS,
+            NFT_TOKEN_ID.as_bytes(),
+            &[EsdtLocalRole::NftCreate],
+        );
+
+        Self { world }
+    }
+
+    fn create_nft(
+        &mut self,
+        name: &str,
+        royalties: u64,
+        attributes: nft_minter_proxy::NftAttributes<StaticApi>,
+    ) -> u64 {
+        let mut uris = MultiValueEncoded::new();
+        uris.push(ManagedBuffer::from("https://example.com/item.png"));
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(NFT_MINTER_ADDRESS)
+            .typed(nft_minter_proxy::NftMinterProxy)
+            .create_nft(USER_ADDRESS, name, royalties, attributes, uris)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Marks the collection as semi-fungible, as `issueSftCollection` would.
+    fn set_semi_fungible(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(NFT_MINTER_ADDRESS)
+            .whitebox(nft_minter::contract_obj, |sc| {
+                sc.semi_fungible().set(true);
+            });
+    }
+
+    fn create_sft(
+        &mut self,
+        amount: u64,
+        name: &str,
+        attributes: nft_minter_proxy::NftAttributes<StaticApi>,
+    ) -> u64 {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(NFT_MINTER_ADDRESS)
+            .typed(nft_minter_proxy::NftMinterProxy)
+            .create_sft(
+                USER_ADDRESS,
+                amount,
+                name,
+                0u64,
+                attributes,
+                MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new(),
+            )
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn set_mint_config(&mut self, attributes: nft_minter_proxy::NftAttributes<StaticApi>) {
+        let template = nft_minter_proxy::MintTemplate {
+            name: ManagedBuffer::from("Ticket"),
+            royalties: BigUint::from(250u64),
+            attributes,
+            uris: ManagedVec::new(),
+        };
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(NFT_MINTER_ADDRESS)
+            .typed(nft_minter_proxy::NftMinterProxy)
+            .set_mint_config(
+                template,
+                EgldOrEsdtTokenIdentifier::egld(),
+                MINT_PRICE,
+                0u32,
+            )
+            .run();
+    }
+
+    fn mint(&mut self) -> u64 {
+        self.world
+            .tx()
+            .from(USER_ADDRESS)
+            .to(NFT_MINTER_ADDRESS)
+            .typed(nft_minter_proxy::NftMinterProxy)
+            .mint()
+            .egld(MINT_PRICE)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Reads the NFT back from the user's account and checks its `TopDecode`d attributes.
+    fn check_user_nft(&mut self, nonce: u64, name: &str, expected: &ExpectedAttributes) {
+        self.world
+            .query()
+            .to(NFT_MINTER_ADDRESS)
+            .whitebox(nft_minter::contract_obj, |sc| {
+                let token_data = sc.blockchain().get_esdt_token_data(
+                    &USER_ADDRESS.to_managed_address(),
+                    &NFT_TOKEN_ID.to_token_identifier(),
+                    nonce,
+                );
+                assert_eq!(token_data.name, ManagedBuffer::from(name));
+
+                let decoded: NftAttributes<DebugApi> = token_data.decode_attributes();
+                assert_eq!(decoded.edition, expected.edition);
+                assert_eq!(decoded.metadata, ManagedBuffer::from(METADATA));
+                assert_eq!(decoded.tags.len(), expected.tags.len());
+                for (decoded_tag, expected_tag) in decoded.tags.iter().zip(expected.tags) {
+                    assert_eq!(*decoded_tag, ManagedBuffer::from(*expected_tag));
+                }
+            });
+    }
+}
+
+#[test]
+fn nft_minter_blackbox_owner_mint_attributes_round_trip() {
+    let mut state = NftMinterTestState::new();
+    let expected = ExpectedAttributes {
+        edition: 0,
+        tags: &["rare", "gold"],
+    };
+
+    let nonce = state.create_nft("Genesis", 500, expected.to_proxy_attributes());
+    assert_eq!(nonce, 1);
+
+    state
+        .world
+        .check_account(USER_ADDRESS)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, nonce, 1u64, expected.to_proxy_attributes());
+    state.check_user_nft(nonce, "Genesis", &expected);
+}
+
+#[test]
+fn nft_minter_blackbox_paid_mint_numbers_editions() {
+    let mut state = NftMinterTestState::new();
+    state.set_mint_config(
+        ExpectedAttributes {
+            edition: 0,
+            tags: &["ticket"],
+        }
+        .to_proxy_attributes(),
+    );
+
+    for edition in 1..=3u64 {
+        let nonce = state.mint();
+        state.check_user_nft(
+            nonce,
+            "Ticket",
+            &ExpectedAttributes {
+                edition,
+                tags: &["ticket"],
+            },
+        );
+    }
+
+    state
+        .world
+        .check_account(NFT_MINTER_ADDRESS)
+        .balance(3 * MINT_PRICE);
+}
+
+#[test]
+fn nft_minter_blackbox_sft_quantity_attributes_round_trip() {
+    let mut state = NftMinterTestState::new();
+    state.set_semi_fungible();
+    let expected = ExpectedAttributes {
+        edition: 0,
+        tags: &["pass"],
+    };
+
+    let nonce = state.create_sft(25, "Pass", expected.to_proxy_attributes());
+    assert_eq!(nonce, 1);
+
+    state
+        .world
+        .check_account(USER_ADDRESS)
+        .esdt_nft_balance_and_attributes(
+            NFT_TOKEN_ID,
+            nonce,
+            25u64,
+            expected.to_proxy_attributes(),
+        );
+    state.check_user_nft(nonce, "Pass", &expected);
+}
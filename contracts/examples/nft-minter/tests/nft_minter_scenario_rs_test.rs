@@ -0,0 +1,36 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/nft-minter");
+    blockchain.register_contract(
+        "mxsc:output/nft-minter.mxsc.json",
+        nft_minter::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn nft_minter_create_rs() {
+    world().run("scenarios/nft-minter-create.scen.json");
+}
+
+#[test]
+fn nft_minter_issue_rs() {
+    world().run("scenarios/nft-minter-issue.scen.json");
+}
+
+#[test]
+fn nft_minter_paid_mint_rs() {
+    world().run("scenarios/nft-minter-paid-mint.scen.json");
+}
+
+#[test]
+fn nft_minter_price_token_change_rs() {
+    world().run("scenarios/nft-minter-price-token-change.scen.json");
+}
+
+#[test]
+fn nft_minter_sft_rs() {
+    world().run("scenarios/nft-minter-sft.scen.json");
+}
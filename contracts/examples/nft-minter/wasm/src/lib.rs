@@ -0,0 +1,41 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           15
+// Async Callback:                       1
+// Total number of exported functions:  18
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    nft_minter
+    (
+        init => init
+        upgrade => upgrade
+        issueCollection => issue_collection
+        issueSftCollection => issue_sft_collection
+        createNft => create_nft
+        createSft => create_sft
+        setMintConfig => set_mint_config
+        mint => mint
+        withdraw => withdraw
+        getNftTokenId => nft_token
+        isSemiFungible => semi_fungible
+        getMintTemplate => mint_template
+        getMintPriceToken => mint_price_token
+        getMintPrice => mint_price
+        getMaxPerWallet => max_per_wallet
+        getMintedCount => minted_count
+        getPaidMintCount => paid_mint_count
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback! { nft_minter }
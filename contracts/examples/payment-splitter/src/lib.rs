@@ -0,0 +1,208 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod payment_splitter_events;
+pub mod payment_splitter_proxy;
+
+pub const ERR_NO_PAYEES: &str = "no payees";
+pub const ERR_ZERO_SHARES: &str = "shares cannot be zero";
+pub const ERR_DUPLICATE_PAYEE: &str = "duplicate payee";
+pub const ERR_SHARES_OVERFLOW: &str = "total shares overflow";
+pub const ERR_ZERO_AMOUNT: &str = "amount cannot be zero";
+pub const ERR_NOT_FUNGIBLE: &str = "only fungible tokens are accepted";
+pub const ERR_NOT_PAYEE: &str = "caller is not a payee";
+pub const ERR_NOTHING_TO_RELEASE: &str = "nothing to release";
+
+/// Splits every EGLD and fungible ESDT payment between fixed payees in proportion to their shares.
+/// Each payee releases their part whenever they want. Entitlements are computed on the total ever
+/// received, so rounding never loses more than one unit per payee and token, and the dust left
+/// behind is shared out again as more payments arrive.
+#[multiversx_sc::contract]
+pub trait PaymentSplitter: payment_splitter_events::PaymentSplitterEventsModule {
+    #[init]
+    fn init(&self, payees: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>) {
+        require!(!payees.is_empty(), ERR_NO_PAYEES);
+
+        let mut total_shares = 0u64;
+        for payee_shares in payees {
+            let (payee, shares) = payee_shares.into_tuple();
+            require!(shares > 0, ERR_ZERO_SHARES);
+            require!(self.payees().insert(payee.clone()), ERR_DUPLICATE_PAYEE);
+            self.shares(&payee).set(shares);
+            total_shares = total_shares
+                .checked_add(shares)
+                .unwrap_or_else(|| sc_panic!(ERR_SHARES_OVERFLOW));
+        }
+        self.total_shares().set(total_shares);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Accepts EGLD or any number of fungible ESDTs, to be split between the payees.
+    #[payable("*")]
+    #[endpoint]
+    fn deposit(&self) {
+        let caller = self.blockchain().get_caller();
+        match self.call_value().any_payment() {
+            EgldOrMultiEsdtPayment::Egld(amount) => {
+                require!(amount > 0, ERR_ZERO_AMOUNT);
+                self.receive_payment(&caller, EgldOrEsdtTokenIdentifier::egld(), &amount);
+            }
+            EgldOrMultiEsdtPayment::MultiEsdt(payments) => {
+                require!(!payments.is_empty(), ERR_ZERO_AMOUNT);
+                for payment in payments.iter() {
+                    require!(payment.token_nonce == 0, ERR_NOT_FUNGIBLE);
+                    self.receive_payment(
+                        &caller,
+                        EgldOrEsdtTokenIdentifier::esdt(payment.token_identifier.clone()),
+                        &payment.amount,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Sends the caller their releasable amount of `token_identifier`. Returns the amount sent.
+    #[endpoint]
+    fn release(&self, token_identifier: EgldOrEsdtTokenIdentifier) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        require!(self.payees().contains(&caller), ERR_NOT_PAYEE);
+
+        let amount = self.release_token(&caller, &token_identifier);
+        require!(amount > 0, ERR_NOTHING_TO_RELEASE);
+        amount
+    }
+
+    /// Sends the caller their releasable amount of each of `token_identifiers`, or of every
+    /// known token when none are given. Returns the payments sent.
+    #[endpoint(releaseAll)]
+    fn release_all(
+        &self,
+        token_identifiers: MultiValueEncoded<EgldOrEsdtTokenIdentifier>,
+    ) -> MultiValueEncoded<EgldOrEsdtTokenPayment> {
+        let caller = self.blockchain().get_caller();
+        require!(self.payees().contains(&caller), ERR_NOT_PAYEE);
+
+        let token_identifiers: ManagedVec<EgldOrEsdtTokenIdentifier> =
+            if token_identifiers.is_empty() {
+                self.tokens().iter().collect()
+            } else {
+                token_identifiers.to_vec()
+            };
+
+        let mut released = MultiValueEncoded::new();
+        for token_identifier in token_identifiers.iter() {
+            let amount = self.release_token(&caller, &token_identifier);
+            if amount > 0 {
+                released.push(EgldOrEsdtTokenPayment::new(
+                    token_identifier.clone(),
+                    0,
+                    amount,
+                ));
+            }
+        }
+        require!(!released.is_empty(), ERR_NOTHING_TO_RELEASE);
+        released
+    }
+
+    fn receive_payment(
+        &self,
+        from: &ManagedAddress,
+        token_identifier: EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        self.payment_received_event(from, &token_identifier, amount);
+        self.tokens().insert(token_identifier);
+    }
+
+    fn release_token(
+        &self,
+        payee: &ManagedAddress,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+    ) -> BigUint {
+        let amount = self.get_releasable(payee.clone(), token_identifier.clone());
+        if amount == 0 {
+            return amount;
+        }
+
+        // Tokens can also arrive without `deposit`; track them once they are released by name.
+        self.tokens().insert(token_identifier.clone());
+        self.released(payee, token_identifier)
+            .update(|released| *released += &amount);
+        self.total_released(token_identifier)
+            .update(|total| *total += &amount);
+        self.payment_released_event(payee, token_identifier, &amount);
+        self.tx()
+            .to(payee)
+            .egld_or_single_esdt(token_identifier, 0, &amount)
+            .transfer();
+        amount
+    }
+
+    #[view(getPayees)]
+    fn get_payees(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
+        let mut result = MultiValueEncoded::new();
+        for payee in self.payees().iter() {
+            let shares = self.shares(&payee).get();
+            result.push((payee, shares).into());
+        }
+        result
+    }
+
+    /// Everything the contract has received in the token, including what was already released.
+    #[view(getTotalReceived)]
+    fn get_total_received(&self, token_identifier: EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.blockchain().get_sc_balance(&token_identifier, 0)
+            + self.total_released(&token_identifier).get()
+    }
+
+    /// The payee's share of the total received, rounded down, minus what they already released.
+    #[view(getReleasable)]
+    fn get_releasable(
+        &self,
+        payee: ManagedAddress,
+        token_identifier: EgldOrEsdtTokenIdentifier,
+    ) -> BigUint {
+        let shares = self.shares(&payee).get();
+        if shares == 0 {
+            return BigUint::zero();
+        }
+
+        let entitled =
+            self.get_total_received(token_identifier.clone()) * shares / self.total_shares().get();
+        entitled - self.released(&payee, &token_identifier).get()
+    }
+
+    #[storage_mapper("payees")]
+    fn payees(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getShares)]
+    #[storage_mapper("shares")]
+    fn shares(&self, payee: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[view(getTotalShares)]
+    #[storage_mapper("totalShares")]
+    fn total_shares(&self) -> SingleValueMapper<u64>;
+
+    /// Every token received through `deposit` or released by name so far.
+    #[view(getTokens)]
+    #[storage_mapper("tokens")]
+    fn tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getReleased)]
+    #[storage_mapper("released")]
+    fn released(
+        &self,
+        payee: &ManagedAddress,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalReleased)]
+    #[storage_mapper("totalReleased")]
+    fn total_released(
+        &self,
+        token_identifier: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,21 @@
+multiversx_sc::imports!();
+
+/// Incoming and outgoing payments, with the account and the token as topics.
+#[multiversx_sc::module]
+pub trait PaymentSplitterEventsModule {
+    #[event("paymentReceived")]
+    fn payment_received_event(
+        &self,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] token_identifier: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("paymentReleased")]
+    fn payment_released_event(
+        &self,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] token_identifier: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+}
@@ -0,0 +1,26 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/payment-splitter");
+    blockchain.register_contract(
+        "mxsc:output/payment-splitter.mxsc.json",
+        payment_splitter::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn payment_splitter_egld_rs() {
+    world().run("scenarios/payment-splitter-egld.scen.json");
+}
+
+#[test]
+fn payment_splitter_multi_token_rs() {
+    world().run("scenarios/payment-splitter-multi-token.scen.json");
+}
+
+#[test]
+fn payment_splitter_setup_rs() {
+    world().run("scenarios/payment-splitter-setup.scen.json");
+}
@@ -0,0 +1,37 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           11
+// Async Callback (empty):               1
+// Total number of exported functions:  14
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    payment_splitter
+    (
+        init => init
+        upgrade => upgrade
+        deposit => deposit
+        release => release
+        releaseAll => release_all
+        getPayees => get_payees
+        getTotalReceived => get_total_received
+        getReleasable => get_releasable
+        getShares => shares
+        getTotalShares => total_shares
+        getTokens => tokens
+        getReleased => released
+        getTotalReleased => total_released
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,79 @@
+#!/bin/bash
+
+# Build and Deploy Script for Ping-Pong Contract
+# This script automates building and deploying the ping-pong smart contract to MultiversX Localnet
+
+set -e
+
+CONTRACT_NAME="ping-pong"
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+PROJECT_ROOT="$(cd "$SCRIPT_DIR/../../.." && pwd)"
+
+PROXY="${PROXY:-http://localhost:7950}"
+WALLET_PEM="${WALLET_PEM:-$PROJECT_ROOT/wallets/wallet-owner.pem}"
+
+# 1 EGLD per ping, pong allowed one hour after deploy
+PING_AMOUNT="${PING_AMOUNT:-1000000000000000000}"
+DURATION_IN_SECONDS="${DURATION_IN_SECONDS:-3600}"
+
+echo "================================"
+echo "Building $CONTRACT_NAME contract..."
+echo "================================"
+
+cd "$SCRIPT_DIR"
+
+# Build the contract
+if [ ! -f "Cargo.toml" ]; then
+    echo "Error: Cargo.toml not found. Please ensure contract source exists."
+    exit 1
+fi
+
+sc-meta all build
+
+if [ $? -ne 0 ]; then
+    echo "Build failed!"
+    exit 1
+fi
+
+echo ""
+echo "Build successful!"
+echo ""
+
+# Check if wallet exists
+if [ ! -f "$WALLET_PEM" ]; then
+    echo "Warning: Wallet file not found at $WALLET_PEM"
+    echo "Please ensure you have a wallet configured."
+    exit 1
+fi
+
+echo "================================"
+echo "Deploying $CONTRACT_NAME to Localnet..."
+echo "================================"
+
+# Find the WASM file
+WASM_FILE=$(find output -name "*.wasm" | head -n 1)
+
+if [ -z "$WASM_FILE" ]; then
+    echo "Error: WASM file not found in output directory"
+    exit 1
+fi
+
+echo "Deploying: $WASM_FILE"
+
+# Deploy through the Rust interactor; the deployment is recorded in contracts/deployments.toml
+cd "$SCRIPT_DIR/interactor"
+cargo run --release --bin ping-pong-interact -- \
+    --gateway="$PROXY" \
+    --pem="$WALLET_PEM" \
+    deploy "$PING_AMOUNT" "$DURATION_IN_SECONDS"
+
+if [ $? -eq 0 ]; then
+    echo ""
+    echo "================================"
+    echo "Deployment successful!"
+    echo "================================"
+else
+    echo ""
+    echo "Deployment failed!"
+    exit 1
+fi
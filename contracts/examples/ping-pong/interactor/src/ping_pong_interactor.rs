@@ -0,0 +1,270 @@
+mod ping_pong_interactor_cli;
+mod ping_pong_interactor_config;
+
+use clap::Parser;
+use deployment_registry::{DeploymentRecord, DeploymentRegistry, RegistryError};
+use ping_pong::ping_pong_proxy::{self, UserStatus};
+pub use ping_pong_interactor_config::Config;
+
+use multiversx_sc_snippets::{imports::*, multiversx_sc::contract_base::ContractAbiProvider};
+
+const CONTRACT_NAME: &str = "ping-pong";
+const PING_PONG_CODE_PATH: MxscPath = MxscPath::new("../output/ping-pong.mxsc.json");
+
+pub async fn ping_pong_cli() {
+    env_logger::init();
+
+    let cli = ping_pong_interactor_cli::InteractCli::parse();
+    let mut config = Config::load_config();
+    if let Some(gateway) = cli.gateway {
+        config.gateway_uri = gateway;
+    }
+    if let Some(pem) = cli.pem {
+        config.owner_pem = Some(pem);
+    }
+
+    let mut interact = PingPongInteract::new(config).await;
+    if let Err(err) = run_command(&mut interact, &cli.command).await {
+        println!("{err}");
+    }
+}
+
+async fn run_command(
+    interact: &mut PingPongInteract,
+    command: &Option<ping_pong_interactor_cli::InteractCliCommand>,
+) -> Result<(), RegistryError> {
+    match command {
+        Some(ping_pong_interactor_cli::InteractCliCommand::Deploy(args)) => {
+            interact
+                .deploy(
+                    big_uint(args.ping_amount),
+                    args.duration_in_seconds,
+                    args.activation_timestamp,
+                    args.max_funds.map(big_uint),
+                )
+                .await;
+        }
+        Some(ping_pong_interactor_cli::InteractCliCommand::Upgrade) => {
+            interact.upgrade().await?;
+        }
+        Some(ping_pong_interactor_cli::InteractCliCommand::Ping) => {
+            interact.ping().await?;
+        }
+        Some(ping_pong_interactor_cli::InteractCliCommand::Pong) => {
+            interact.pong().await?;
+        }
+        Some(ping_pong_interactor_cli::InteractCliCommand::Status) => {
+            println!("ping amount: {}", interact.ping_amount().await?);
+            println!("deadline: {}", interact.deadline().await?);
+            let owner_address = interact.owner_address.clone();
+            println!("status: {:?}", interact.user_status(&owner_address).await?);
+        }
+        None => {}
+    }
+
+    Ok(())
+}
+
+fn big_uint(value: u128) -> BigUint<StaticApi> {
+    BigUint::from_bytes_be(&value.to_be_bytes())
+}
+
+pub struct PingPongInteract {
+    pub interactor: Interactor,
+    pub owner_address: Bech32Address,
+    pub network: String,
+    pub registry: DeploymentRegistry,
+}
+
+impl PingPongInteract {
+    pub async fn new(config: Config) -> Self {
+        let mut interactor = Interactor::new(&config.gateway_uri)
+            .await
+            .use_chain_simulator(config.use_chain_simulator());
+        interactor.set_current_dir_from_workspace("examples/ping-pong/interactor");
+
+        let owner_address = interactor.register_wallet(config.owner_wallet()).await;
+
+        // generate blocks until ESDTSystemSCActivationEpoch in order to be able to use the chain simulator
+        interactor.generate_blocks_until_epoch(1).await.unwrap();
+
+        let registry = DeploymentRegistry::load_default().unwrap_or_else(|err| {
+            println!("deployment registry not loaded: {err}");
+            DeploymentRegistry::default()
+        });
+
+        PingPongInteract {
+            interactor,
+            owner_address: owner_address.into(),
+            network: config.network,
+            registry,
+        }
+    }
+
+    pub async fn deploy(
+        &mut self,
+        ping_amount: BigUint<StaticApi>,
+        duration_in_seconds: u64,
+        activation_timestamp: Option<u64>,
+        max_funds: Option<BigUint<StaticApi>>,
+    ) {
+        let (new_address, tx_hash) = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .gas(10_000_000)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .init(
+                ping_amount,
+                duration_in_seconds,
+                OptionalValue::from(activation_timestamp),
+                OptionalValue::from(max_funds),
+            )
+            .code(PING_PONG_CODE_PATH)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .returns(ReturnsNewBech32Address)
+            .returns(ReturnsTxHash)
+            .run()
+            .await;
+
+        println!("new address: {new_address}");
+        if let Err(err) = self
+            .record_deployment(new_address, tx_hash.as_bytes())
+            .await
+        {
+            println!("deployment not recorded in the registry: {err}");
+        }
+    }
+
+    pub async fn upgrade(&mut self) -> Result<(), RegistryError> {
+        let ping_pong_address = self.ping_pong_address()?;
+        let tx_hash = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&ping_pong_address)
+            .gas(10_000_000)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .upgrade()
+            .code(PING_PONG_CODE_PATH)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .returns(ReturnsTxHash)
+            .run()
+            .await;
+
+        println!("upgraded {ping_pong_address}");
+        if let Err(err) = self
+            .record_deployment(ping_pong_address, tx_hash.as_bytes())
+            .await
+        {
+            println!("deployment not recorded in the registry: {err}");
+        }
+
+        Ok(())
+    }
+
+    pub async fn ping(&mut self) -> Result<(), RegistryError> {
+        let ping_pong_address = self.ping_pong_address()?;
+        let ping_amount = self.ping_amount().await?;
+        self.interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&ping_pong_address)
+            .gas(10_000_000)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .ping()
+            .egld(BigUint::from_bytes_be(&ping_amount.to_bytes_be()))
+            .run()
+            .await;
+
+        println!("pinged");
+
+        Ok(())
+    }
+
+    pub async fn pong(&mut self) -> Result<(), RegistryError> {
+        let ping_pong_address = self.ping_pong_address()?;
+        self.interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&ping_pong_address)
+            .gas(10_000_000)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .pong()
+            .run()
+            .await;
+
+        println!("ponged");
+
+        Ok(())
+    }
+
+    pub async fn ping_amount(&mut self) -> Result<RustBigUint, RegistryError> {
+        let ping_pong_address = self.ping_pong_address()?;
+        let ping_amount = self
+            .interactor
+            .query()
+            .to(&ping_pong_address)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .ping_amount()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+        Ok(ping_amount)
+    }
+
+    pub async fn deadline(&mut self) -> Result<u64, RegistryError> {
+        let ping_pong_address = self.ping_pong_address()?;
+        let deadline = self
+            .interactor
+            .query()
+            .to(&ping_pong_address)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .deadline()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+        Ok(deadline)
+    }
+
+    pub async fn user_status(&mut self, user: &Bech32Address) -> Result<UserStatus, RegistryError> {
+        let ping_pong_address = self.ping_pong_address()?;
+        let user_status = self
+            .interactor
+            .query()
+            .to(&ping_pong_address)
+            .typed(ping_pong_proxy::PingPongProxy)
+            .user_status(user)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+        Ok(user_status)
+    }
+
+    /// Address of the ping-pong contract recorded for the configured network.
+    pub fn ping_pong_address(&self) -> Result<Bech32Address, RegistryError> {
+        self.registry.address(&self.network, CONTRACT_NAME).cloned()
+    }
+
+    async fn record_deployment(
+        &mut self,
+        address: Bech32Address,
+        tx_hash: &[u8],
+    ) -> Result<(), RegistryError> {
+        let abi_version = ping_pong::AbiProvider::abi()
+            .build_info
+            .contract_crate
+            .version;
+        let record = DeploymentRecord::fetch(
+            &self.interactor,
+            address,
+            self.owner_address.clone(),
+            tx_hash,
+            abi_version,
+        )
+        .await?;
+
+        self.registry
+            .record_and_save(&self.network, CONTRACT_NAME, record)
+    }
+}
@@ -0,0 +1,53 @@
+use clap::{Args, Parser, Subcommand};
+
+/// Ping Pong Interact CLI
+#[derive(Default, PartialEq, Eq, Debug, Parser)]
+#[command(version, about)]
+#[command(propagate_version = true)]
+pub struct InteractCli {
+    /// Overrides `gateway_uri` from config.toml
+    #[arg(long, global = true)]
+    pub gateway: Option<String>,
+
+    /// Overrides `owner_pem` from config.toml
+    #[arg(long, global = true)]
+    pub pem: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<InteractCliCommand>,
+}
+
+/// Ping Pong Interact CLI Commands
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum InteractCliCommand {
+    #[command(name = "deploy", about = "Deploy contract")]
+    Deploy(DeployArgs),
+    #[command(name = "upgrade", about = "Upgrade contract")]
+    Upgrade,
+    #[command(name = "ping", about = "Send the ping amount")]
+    Ping,
+    #[command(name = "pong", about = "Withdraw the ping amount after the deadline")]
+    Pong,
+    #[command(
+        name = "status",
+        about = "Print contract settings and the caller status"
+    )]
+    Status,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct DeployArgs {
+    /// Fixed ping amount, in atto-EGLD
+    pub ping_amount: u128,
+
+    /// Seconds after activation during which pings are accepted
+    pub duration_in_seconds: u64,
+
+    /// Activation timestamp; defaults to the deploy block
+    #[arg(long)]
+    pub activation_timestamp: Option<u64>,
+
+    /// Maximum EGLD the contract accepts, in atto-EGLD
+    #[arg(long)]
+    pub max_funds: Option<u128>,
+}
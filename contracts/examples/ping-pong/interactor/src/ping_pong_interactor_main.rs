@@ -0,0 +1,6 @@
+use multiversx_sc_snippets::imports::*;
+
+#[tokio::main]
+async fn main() {
+    ping_pong_interactor::ping_pong_cli().await;
+}
@@ -0,0 +1,33 @@
+use multiversx_sc_snippets::imports::*;
+use ping_pong::ping_pong_proxy::UserStatus;
+use ping_pong_interactor::{Config, PingPongInteract};
+
+#[tokio::test]
+#[cfg_attr(not(feature = "chain-simulator-tests"), ignore)]
+async fn ping_pong_simulator_ping_then_pong() {
+    let mut interact = PingPongInteract::new(Config::chain_simulator_config()).await;
+    let owner_address = interact.owner_address.clone();
+
+    interact
+        .deploy(BigUint::from(1_000_000_000_000_000u64), 6, None, None)
+        .await;
+    assert_eq!(
+        interact.user_status(&owner_address).await.unwrap(),
+        UserStatus::New
+    );
+
+    interact.ping().await.unwrap();
+    assert_eq!(
+        interact.user_status(&owner_address).await.unwrap(),
+        UserStatus::Registered
+    );
+
+    // blocks are 6 seconds apart on the chain simulator
+    interact.interactor.generate_blocks(2).await.unwrap();
+
+    interact.pong().await.unwrap();
+    assert_eq!(
+        interact.user_status(&owner_address).await.unwrap(),
+        UserStatus::Withdrawn
+    );
+}
@@ -0,0 +1,123 @@
+#![no_std]
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub mod ping_pong_proxy;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum UserStatus {
+    New,
+    Registered,
+    Withdrawn,
+}
+
+/// Every user can `ping` once with exactly `pingAmount` EGLD before the deadline,
+/// then `pong` to get it back once the deadline has passed.
+#[multiversx_sc::contract]
+pub trait PingPong {
+    /// `duration_in_seconds` counts from `opt_activation_timestamp`, or from the deploy block.
+    /// `opt_max_funds` caps the total EGLD the contract accepts.
+    #[init]
+    #[allow_multiple_var_args]
+    fn init(
+        &self,
+        ping_amount: BigUint,
+        duration_in_seconds: u64,
+        opt_activation_timestamp: OptionalValue<u64>,
+        opt_max_funds: OptionalValue<BigUint>,
+    ) {
+        require!(ping_amount > 0, "ping amount cannot be zero");
+        self.ping_amount().set(&ping_amount);
+
+        let activation_timestamp = opt_activation_timestamp
+            .into_option()
+            .unwrap_or_else(|| self.blockchain().get_block_timestamp());
+        self.activation_timestamp().set(activation_timestamp);
+        self.deadline()
+            .set(activation_timestamp + duration_in_seconds);
+
+        if let Some(max_funds) = opt_max_funds.into_option() {
+            self.max_funds().set(max_funds);
+        }
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[payable("EGLD")]
+    #[endpoint]
+    fn ping(&self) {
+        let payment = self.call_value().egld();
+        require!(
+            *payment == self.ping_amount().get(),
+            "the payment must match the fixed ping amount"
+        );
+
+        let block_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            block_timestamp >= self.activation_timestamp().get(),
+            "smart contract not active yet"
+        );
+        require!(
+            block_timestamp < self.deadline().get(),
+            "deadline has passed"
+        );
+
+        if !self.max_funds().is_empty() {
+            let balance = self
+                .blockchain()
+                .get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+            require!(balance <= self.max_funds().get(), "smart contract full");
+        }
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.user_status(&caller).get() == UserStatus::New,
+            "can only ping once"
+        );
+
+        self.user_status(&caller).set(UserStatus::Registered);
+    }
+
+    #[endpoint]
+    fn pong(&self) {
+        require!(
+            self.blockchain().get_block_timestamp() >= self.deadline().get(),
+            "can't withdraw before deadline"
+        );
+
+        let caller = self.blockchain().get_caller();
+        match self.user_status(&caller).get() {
+            UserStatus::New => sc_panic!("can't pong, never pinged"),
+            UserStatus::Withdrawn => sc_panic!("already withdrawn"),
+            UserStatus::Registered => {}
+        }
+
+        self.user_status(&caller).set(UserStatus::Withdrawn);
+
+        let ping_amount = self.ping_amount().get();
+        self.tx().to(&caller).egld(&ping_amount).transfer();
+    }
+
+    #[view(getPingAmount)]
+    #[storage_mapper("pingAmount")]
+    fn ping_amount(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getActivationTimestamp)]
+    #[storage_mapper("activationTimestamp")]
+    fn activation_timestamp(&self) -> SingleValueMapper<u64>;
+
+    #[view(getDeadline)]
+    #[storage_mapper("deadline")]
+    fn deadline(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMaxFunds)]
+    #[storage_mapper("maxFunds")]
+    fn max_funds(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getUserStatus)]
+    #[storage_mapper("userStatus")]
+    fn user_status(&self, address: &ManagedAddress) -> SingleValueMapper<UserStatus>;
+}
@@ -0,0 +1,51 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/ping-pong");
+    blockchain.register_contract(
+        "mxsc:output/ping-pong.mxsc.json",
+        ping_pong::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn ping_pong_ping_after_deadline_rs() {
+    world().run("scenarios/ping-pong-ping-after-deadline.scen.json");
+}
+
+#[test]
+fn ping_pong_ping_full_rs() {
+    world().run("scenarios/ping-pong-ping-full.scen.json");
+}
+
+#[test]
+fn ping_pong_ping_ok_rs() {
+    world().run("scenarios/ping-pong-ping-ok.scen.json");
+}
+
+#[test]
+fn ping_pong_ping_twice_rs() {
+    world().run("scenarios/ping-pong-ping-twice.scen.json");
+}
+
+#[test]
+fn ping_pong_ping_wrong_amount_rs() {
+    world().run("scenarios/ping-pong-ping-wrong-amount.scen.json");
+}
+
+#[test]
+fn ping_pong_pong_ok_rs() {
+    world().run("scenarios/ping-pong-pong-ok.scen.json");
+}
+
+#[test]
+fn ping_pong_pong_too_early_rs() {
+    world().run("scenarios/ping-pong-pong-too-early.scen.json");
+}
+
+#[test]
+fn ping_pong_pong_without_ping_rs() {
+    world().run("scenarios/ping-pong-pong-without-ping.scen.json");
+}
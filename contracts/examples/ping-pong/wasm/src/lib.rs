@@ -0,0 +1,33 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            7
+// Async Callback (empty):               1
+// Total number of exported functions:  10
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    ping_pong
+    (
+        init => init
+        upgrade => upgrade
+        ping => ping
+        pong => pong
+        getPingAmount => ping_amount
+        getActivationTimestamp => activation_timestamp
+        getDeadline => deadline
+        getMaxFunds => max_funds
+        getUserStatus => user_status
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
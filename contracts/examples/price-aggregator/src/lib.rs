@@ -0,0 +1,276 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod price_aggregator_events;
+pub mod price_aggregator_proxy;
+pub mod price_feed;
+
+use price_feed::{PriceFeed, TokenPair};
+
+pub const ERR_PAUSED: &str = "contract is paused";
+pub const ERR_NOT_SUBMITTER: &str = "only whitelisted submitters can submit prices";
+pub const ERR_INVALID_SUBMISSION_COUNT: &str =
+    "submission count must be between 1 and the number of submitters";
+pub const ERR_ZERO_ROUND_DURATION: &str = "max round duration cannot be zero";
+pub const ERR_UNKNOWN_PAIR: &str = "pair not configured";
+pub const ERR_WRONG_DECIMALS: &str = "wrong decimals";
+pub const ERR_ZERO_PRICE: &str = "price cannot be zero";
+pub const ERR_TIMESTAMP_IN_FUTURE: &str = "submission timestamp is in the future";
+pub const ERR_STALE_SUBMISSION: &str = "stale submission";
+pub const ERR_NO_PRICE: &str = "no price for this pair";
+
+/// Whitelisted submitters push prices for configured token pairs. Once `submissionCount`
+/// of them have submitted in the same round, the median becomes the pair's latest price feed.
+/// A round that does not reach quorum within `maxRoundDuration` seconds is discarded,
+/// and so are submissions older than that or older than the latest feed.
+#[multiversx_sc::contract]
+pub trait PriceAggregator: price_aggregator_events::PriceAggregatorEventsModule {
+    #[init]
+    fn init(
+        &self,
+        submission_count: usize,
+        max_round_duration: u64,
+        submitters: MultiValueEncoded<ManagedAddress>,
+    ) {
+        require!(max_round_duration > 0, ERR_ZERO_ROUND_DURATION);
+        self.max_round_duration().set(max_round_duration);
+
+        self.submitters().extend(submitters);
+        self.set_submission_count(submission_count);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Adds a price to the current round of the pair, replacing the caller's earlier one
+    /// in the same round. Publishes the median when the round reaches quorum.
+    #[endpoint]
+    fn submit(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        submission_timestamp: u64,
+        price: BigUint,
+        decimals: u8,
+    ) {
+        require!(!self.paused().get(), ERR_PAUSED);
+        let caller = self.blockchain().get_caller();
+        require!(self.submitters().contains(&caller), ERR_NOT_SUBMITTER);
+
+        let pair_decimals = self.pair_decimals(&from, &to);
+        require!(!pair_decimals.is_empty(), ERR_UNKNOWN_PAIR);
+        require!(decimals == pair_decimals.get(), ERR_WRONG_DECIMALS);
+        require!(price > 0, ERR_ZERO_PRICE);
+
+        let now = self.blockchain().get_block_timestamp();
+        let max_round_duration = self.max_round_duration().get();
+        require!(submission_timestamp <= now, ERR_TIMESTAMP_IN_FUTURE);
+        require!(
+            now - submission_timestamp <= max_round_duration,
+            ERR_STALE_SUBMISSION
+        );
+        let latest_feed = self.latest_feed(&from, &to);
+        if !latest_feed.is_empty() {
+            require!(
+                submission_timestamp >= latest_feed.get().timestamp,
+                ERR_STALE_SUBMISSION
+            );
+        }
+
+        let mut submissions = self.round_submissions(&from, &to);
+        if !submissions.is_empty() {
+            let round_start = self.round_start(&from, &to).get();
+            if now - round_start > max_round_duration {
+                self.round_discarded_event(&from, &to, round_start, submissions.len());
+                submissions.clear();
+            }
+        }
+        if submissions.is_empty() {
+            self.round_start(&from, &to).set(now);
+        }
+
+        submissions.insert(caller, price);
+        if submissions.len() >= self.submission_count().get() {
+            self.publish_round(from, to, decimals);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(addSubmitters)]
+    fn add_submitters(&self, submitters: MultiValueEncoded<ManagedAddress>) {
+        self.submitters().extend(submitters);
+    }
+
+    /// Their prices are also dropped from the rounds in progress.
+    #[only_owner]
+    #[endpoint(removeSubmitters)]
+    fn remove_submitters(&self, submitters: MultiValueEncoded<ManagedAddress>) {
+        for submitter in submitters {
+            self.submitters().swap_remove(&submitter);
+            for pair in self.pairs().iter() {
+                self.round_submissions(&pair.from, &pair.to)
+                    .remove(&submitter);
+            }
+        }
+        require!(
+            self.submission_count().get() <= self.submitters().len(),
+            ERR_INVALID_SUBMISSION_COUNT
+        );
+    }
+
+    /// Rounds in progress that already meet a lowered quorum publish on their next submission.
+    #[only_owner]
+    #[endpoint(setSubmissionCount)]
+    fn set_submission_count(&self, submission_count: usize) {
+        require!(
+            submission_count > 0 && submission_count <= self.submitters().len(),
+            ERR_INVALID_SUBMISSION_COUNT
+        );
+        self.submission_count().set(submission_count);
+    }
+
+    #[only_owner]
+    #[endpoint(setMaxRoundDuration)]
+    fn set_max_round_duration(&self, max_round_duration: u64) {
+        require!(max_round_duration > 0, ERR_ZERO_ROUND_DURATION);
+        self.max_round_duration().set(max_round_duration);
+    }
+
+    /// Enables submissions for a pair. Changing the decimals of a configured pair
+    /// drops its round in progress, since those prices use the old scale.
+    #[only_owner]
+    #[endpoint(setPairDecimals)]
+    fn set_pair_decimals(&self, from: ManagedBuffer, to: ManagedBuffer, decimals: u8) {
+        self.round_submissions(&from, &to).clear();
+        self.pair_decimals(&from, &to).set(decimals);
+        self.pairs().insert(TokenPair { from, to });
+    }
+
+    #[only_owner]
+    #[endpoint]
+    fn pause(&self) {
+        self.paused().set(true);
+    }
+
+    #[only_owner]
+    #[endpoint]
+    fn unpause(&self) {
+        self.paused().clear();
+    }
+
+    fn publish_round(&self, from: ManagedBuffer, to: ManagedBuffer, decimals: u8) {
+        let mut submissions = self.round_submissions(&from, &to);
+        let mut prices = ManagedVec::<Self::Api, BigUint>::new();
+        for price in submissions.values() {
+            prices.push(price);
+        }
+        submissions.clear();
+
+        let latest_feed = self.latest_feed(&from, &to);
+        let round_id = if latest_feed.is_empty() {
+            1
+        } else {
+            latest_feed.get().round_id + 1
+        };
+        let feed = PriceFeed {
+            round_id,
+            from,
+            to,
+            timestamp: self.blockchain().get_block_timestamp(),
+            price: self.median(prices),
+            decimals,
+        };
+        self.new_round_event(&feed.from, &feed.to, round_id, &feed);
+        latest_feed.set(feed);
+    }
+
+    /// Middle value, or the floor of the mean of the two middle values for an even count.
+    fn median(&self, mut prices: ManagedVec<BigUint>) -> BigUint {
+        prices.sort_unstable();
+        let middle = prices.len() / 2;
+        if prices.len() % 2 == 1 {
+            prices.get(middle).clone()
+        } else {
+            (prices.get(middle - 1).clone() + &*prices.get(middle)) / 2u32
+        }
+    }
+
+    #[view(latestPriceFeed)]
+    fn latest_price_feed(&self, from: ManagedBuffer, to: ManagedBuffer) -> PriceFeed<Self::Api> {
+        let latest_feed = self.latest_feed(&from, &to);
+        require!(!latest_feed.is_empty(), ERR_NO_PRICE);
+        latest_feed.get()
+    }
+
+    /// Same as `latestPriceFeed`, without failing for pairs that have no price yet.
+    #[view(latestPriceFeedOptional)]
+    fn latest_price_feed_optional(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> OptionalValue<PriceFeed<Self::Api>> {
+        let latest_feed = self.latest_feed(&from, &to);
+        if latest_feed.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(latest_feed.get())
+        }
+    }
+
+    /// Prices submitted so far in the pair's current round.
+    #[view(getRoundSubmissions)]
+    fn get_round_submissions(
+        &self,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for (submitter, price) in self.round_submissions(&from, &to).iter() {
+            result.push((submitter, price).into());
+        }
+        result
+    }
+
+    #[view(getSubmitters)]
+    #[storage_mapper("submitters")]
+    fn submitters(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getSubmissionCount)]
+    #[storage_mapper("submissionCount")]
+    fn submission_count(&self) -> SingleValueMapper<usize>;
+
+    #[view(getMaxRoundDuration)]
+    #[storage_mapper("maxRoundDuration")]
+    fn max_round_duration(&self) -> SingleValueMapper<u64>;
+
+    #[view(getPairs)]
+    #[storage_mapper("pairs")]
+    fn pairs(&self) -> UnorderedSetMapper<TokenPair<Self::Api>>;
+
+    #[view(getPairDecimals)]
+    #[storage_mapper("pairDecimals")]
+    fn pair_decimals(&self, from: &ManagedBuffer, to: &ManagedBuffer) -> SingleValueMapper<u8>;
+
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("latestFeed")]
+    fn latest_feed(
+        &self,
+        from: &ManagedBuffer,
+        to: &ManagedBuffer,
+    ) -> SingleValueMapper<PriceFeed<Self::Api>>;
+
+    #[storage_mapper("roundSubmissions")]
+    fn round_submissions(
+        &self,
+        from: &ManagedBuffer,
+        to: &ManagedBuffer,
+    ) -> MapMapper<ManagedAddress, BigUint>;
+
+    /// Block timestamp of the first submission of the pair's current round.
+    #[storage_mapper("roundStart")]
+    fn round_start(&self, from: &ManagedBuffer, to: &ManagedBuffer) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,26 @@
+multiversx_sc::imports!();
+
+use crate::price_feed::PriceFeed;
+
+/// Round lifecycle events, with the pair tickers as topics.
+#[multiversx_sc::module]
+pub trait PriceAggregatorEventsModule {
+    #[event("newRound")]
+    fn new_round_event(
+        &self,
+        #[indexed] from: &ManagedBuffer,
+        #[indexed] to: &ManagedBuffer,
+        #[indexed] round_id: u64,
+        feed: &PriceFeed<Self::Api>,
+    );
+
+    /// A round that did not reach quorum within `maxRoundDuration`; its submissions were dropped.
+    #[event("roundDiscarded")]
+    fn round_discarded_event(
+        &self,
+        #[indexed] from: &ManagedBuffer,
+        #[indexed] to: &ManagedBuffer,
+        #[indexed] round_start: u64,
+        submission_count: usize,
+    );
+}
@@ -0,0 +1,23 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Pair of tickers a price is quoted for, e.g. `EGLD` in `USD`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct TokenPair<M: ManagedTypeApi> {
+    pub from: ManagedBuffer<M>,
+    pub to: ManagedBuffer<M>,
+}
+
+/// Median of one completed round: `price` units of `to`, scaled by `10^decimals`, for one `from`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct PriceFeed<M: ManagedTypeApi> {
+    pub round_id: u64,
+    pub from: ManagedBuffer<M>,
+    pub to: ManagedBuffer<M>,
+    /// Block timestamp at which the round reached quorum.
+    pub timestamp: u64,
+    pub price: BigUint<M>,
+    pub decimals: u8,
+}
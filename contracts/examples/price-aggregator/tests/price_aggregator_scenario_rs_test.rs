@@ -0,0 +1,31 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/price-aggregator");
+    blockchain.register_contract(
+        "mxsc:output/price-aggregator.mxsc.json",
+        price_aggregator::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn price_aggregator_admin_rs() {
+    world().run("scenarios/price-aggregator-admin.scen.json");
+}
+
+#[test]
+fn price_aggregator_setup_rs() {
+    world().run("scenarios/price-aggregator-setup.scen.json");
+}
+
+#[test]
+fn price_aggregator_stale_round_rs() {
+    world().run("scenarios/price-aggregator-stale-round.scen.json");
+}
+
+#[test]
+fn price_aggregator_submit_rs() {
+    world().run("scenarios/price-aggregator-submit.scen.json");
+}
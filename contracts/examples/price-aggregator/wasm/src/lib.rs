@@ -0,0 +1,43 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           17
+// Async Callback (empty):               1
+// Total number of exported functions:  20
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    price_aggregator
+    (
+        init => init
+        upgrade => upgrade
+        submit => submit
+        addSubmitters => add_submitters
+        removeSubmitters => remove_submitters
+        setSubmissionCount => set_submission_count
+        setMaxRoundDuration => set_max_round_duration
+        setPairDecimals => set_pair_decimals
+        pause => pause
+        unpause => unpause
+        latestPriceFeed => latest_price_feed
+        latestPriceFeedOptional => latest_price_feed_optional
+        getRoundSubmissions => get_round_submissions
+        getSubmitters => submitters
+        getSubmissionCount => submission_count
+        getMaxRoundDuration => max_round_duration
+        getPairs => pairs
+        getPairDecimals => pair_decimals
+        isPaused => paused
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,302 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod staking_rewards_proxy;
+pub mod unbonding;
+
+use unbonding::UnbondingEntry;
+
+pub const ERR_INVALID_TOKEN: &str = "invalid token";
+pub const ERR_WRONG_TOKEN: &str = "wrong token";
+pub const ERR_ZERO_AMOUNT: &str = "amount cannot be zero";
+pub const ERR_INSUFFICIENT_STAKE: &str = "not enough staked";
+pub const ERR_NOTHING_TO_WITHDRAW: &str = "nothing to withdraw";
+pub const ERR_NOTHING_TO_CLAIM: &str = "nothing to claim";
+pub const ERR_CANNOT_COMPOUND: &str = "can only compound when staking the reward token";
+
+/// Fixed-point scale of the reward-per-share accumulator.
+pub const DIVISION_SAFETY_CONSTANT: u64 = 1_000_000_000_000_000_000;
+
+/// Stakers lock EGLD or an ESDT and earn `rewardsPerBlock` of the reward token,
+/// split pro rata through a reward-per-share accumulator. Rewards come only from
+/// what the owner has topped up; once that runs out, accrual pauses until the next top-up.
+#[multiversx_sc::contract]
+pub trait StakingRewards {
+    #[init]
+    fn init(
+        &self,
+        staking_token: EgldOrEsdtTokenIdentifier,
+        reward_token: TokenIdentifier,
+        rewards_per_block: BigUint,
+        unbonding_blocks: u64,
+    ) {
+        require!(staking_token.is_valid(), ERR_INVALID_TOKEN);
+        require!(reward_token.is_valid_esdt_identifier(), ERR_INVALID_TOKEN);
+
+        self.staking_token().set(staking_token);
+        self.reward_token().set(reward_token);
+        self.rewards_per_block().set(rewards_per_block);
+        self.unbonding_blocks().set(unbonding_blocks);
+        self.last_update_block()
+            .set(self.blockchain().get_block_nonce());
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[payable("*")]
+    #[endpoint]
+    fn stake(&self) {
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(
+            payment.token_identifier == self.staking_token().get() && payment.token_nonce == 0,
+            ERR_WRONG_TOKEN
+        );
+        require!(payment.amount > 0, ERR_ZERO_AMOUNT);
+
+        let caller = self.blockchain().get_caller();
+        self.update_reward_per_share();
+        self.settle_rewards(&caller);
+        self.add_stake(&caller, &payment.amount);
+    }
+
+    /// Stops `amount` from earning rewards. It can be withdrawn after the unbonding period.
+    #[endpoint]
+    fn unstake(&self, amount: BigUint) {
+        require!(amount > 0, ERR_ZERO_AMOUNT);
+
+        let caller = self.blockchain().get_caller();
+        self.update_reward_per_share();
+        self.settle_rewards(&caller);
+
+        let staked = self.staked(&caller).get();
+        require!(amount <= staked, ERR_INSUFFICIENT_STAKE);
+        self.staked(&caller).set(staked - &amount);
+        self.total_staked().update(|total| *total -= &amount);
+
+        let unlock_block = self
+            .blockchain()
+            .get_block_nonce()
+            .saturating_add(self.unbonding_blocks().get());
+        self.unbonding(&caller).update(|entries| {
+            entries.push(UnbondingEntry {
+                amount,
+                unlock_block,
+            })
+        });
+    }
+
+    /// Sends back every unstaked amount whose unbonding period is over. Returns the amount sent.
+    #[endpoint]
+    fn withdraw(&self) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        let current_block = self.blockchain().get_block_nonce();
+
+        let mut withdrawn = BigUint::zero();
+        let mut still_unbonding = ManagedVec::<Self::Api, UnbondingEntry<Self::Api>>::new();
+        for entry in self.unbonding(&caller).get().into_iter() {
+            if entry.unlock_block <= current_block {
+                withdrawn += entry.amount;
+            } else {
+                still_unbonding.push(entry);
+            }
+        }
+        require!(withdrawn > 0, ERR_NOTHING_TO_WITHDRAW);
+
+        if still_unbonding.is_empty() {
+            self.unbonding(&caller).clear();
+        } else {
+            self.unbonding(&caller).set(still_unbonding);
+        }
+        self.tx()
+            .to(&caller)
+            .egld_or_single_esdt(&self.staking_token().get(), 0, &withdrawn)
+            .transfer();
+
+        withdrawn
+    }
+
+    /// Sends the caller their accrued rewards. Returns the amount sent.
+    #[endpoint]
+    fn claim(&self) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        let rewards = self.take_rewards(&caller);
+
+        self.tx()
+            .to(&caller)
+            .single_esdt(&self.reward_token().get(), 0, &rewards)
+            .transfer();
+
+        rewards
+    }
+
+    /// Stakes the caller's accrued rewards. Only possible when the staking token is the reward token.
+    /// Returns the amount added to the stake.
+    #[endpoint]
+    fn compound(&self) -> BigUint {
+        require!(
+            self.staking_token().get()
+                == EgldOrEsdtTokenIdentifier::esdt(self.reward_token().get()),
+            ERR_CANNOT_COMPOUND
+        );
+
+        let caller = self.blockchain().get_caller();
+        let rewards = self.take_rewards(&caller);
+        self.add_stake(&caller, &rewards);
+
+        rewards
+    }
+
+    /// Funds future rewards with the reward token.
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(topUpRewards)]
+    fn top_up_rewards(&self) {
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.reward_token().get(),
+            ERR_WRONG_TOKEN
+        );
+        require!(payment.amount > 0, ERR_ZERO_AMOUNT);
+
+        // blocks that passed with an empty reserve stay unrewarded
+        self.update_reward_per_share();
+        self.reward_reserve()
+            .update(|reserve| *reserve += &payment.amount);
+        self.total_rewards_deposited()
+            .update(|total| *total += &payment.amount);
+    }
+
+    #[only_owner]
+    #[endpoint(setRewardsPerBlock)]
+    fn set_rewards_per_block(&self, rewards_per_block: BigUint) {
+        self.update_reward_per_share();
+        self.rewards_per_block().set(rewards_per_block);
+    }
+
+    /// Rewards for the blocks since the last update, capped at the reserve, and the
+    /// accumulator value that spreads them over the current stake.
+    fn compute_reward_per_share(&self) -> (BigUint, BigUint) {
+        let reward_per_share = self.reward_per_share().get();
+        let total_staked = self.total_staked().get();
+        let blocks = self.blockchain().get_block_nonce() - self.last_update_block().get();
+        if blocks == 0 || total_staked == 0 {
+            return (reward_per_share, BigUint::zero());
+        }
+
+        let mut rewards = self.rewards_per_block().get() * blocks;
+        let reserve = self.reward_reserve().get();
+        if rewards > reserve {
+            rewards = reserve;
+        }
+        let increase = &rewards * DIVISION_SAFETY_CONSTANT / total_staked;
+        (reward_per_share + increase, rewards)
+    }
+
+    fn update_reward_per_share(&self) {
+        let (reward_per_share, rewards) = self.compute_reward_per_share();
+        self.reward_per_share().set(reward_per_share);
+        self.reward_reserve().update(|reserve| *reserve -= rewards);
+        self.last_update_block()
+            .set(self.blockchain().get_block_nonce());
+    }
+
+    /// Rewards earned since `user_reward_per_share`, rounded down.
+    fn earned_rewards(&self, user: &ManagedAddress, reward_per_share: &BigUint) -> BigUint {
+        let paid_reward_per_share = self.user_reward_per_share(user).get();
+        self.staked(user).get() * (reward_per_share - &paid_reward_per_share)
+            / DIVISION_SAFETY_CONSTANT
+    }
+
+    /// Moves what `user` earned into their pending rewards. Call before changing their stake.
+    fn settle_rewards(&self, user: &ManagedAddress) {
+        let reward_per_share = self.reward_per_share().get();
+        let earned = self.earned_rewards(user, &reward_per_share);
+        self.pending_rewards(user)
+            .update(|pending| *pending += earned);
+        self.user_reward_per_share(user).set(reward_per_share);
+    }
+
+    fn take_rewards(&self, user: &ManagedAddress) -> BigUint {
+        self.update_reward_per_share();
+        self.settle_rewards(user);
+
+        let rewards = self.pending_rewards(user).take();
+        require!(rewards > 0, ERR_NOTHING_TO_CLAIM);
+        self.total_rewards_distributed()
+            .update(|total| *total += &rewards);
+
+        rewards
+    }
+
+    fn add_stake(&self, user: &ManagedAddress, amount: &BigUint) {
+        self.staked(user).update(|staked| *staked += amount);
+        self.total_staked().update(|total| *total += amount);
+    }
+
+    /// Rewards `user` could claim right now.
+    #[view(getPendingRewards)]
+    fn get_pending_rewards(&self, user: ManagedAddress) -> BigUint {
+        let (reward_per_share, _) = self.compute_reward_per_share();
+        self.pending_rewards(&user).get() + self.earned_rewards(&user, &reward_per_share)
+    }
+
+    #[view(getStakingToken)]
+    #[storage_mapper("stakingToken")]
+    fn staking_token(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getRewardToken)]
+    #[storage_mapper("rewardToken")]
+    fn reward_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getRewardsPerBlock)]
+    #[storage_mapper("rewardsPerBlock")]
+    fn rewards_per_block(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getUnbondingBlocks)]
+    #[storage_mapper("unbondingBlocks")]
+    fn unbonding_blocks(&self) -> SingleValueMapper<u64>;
+
+    #[view(getTotalStaked)]
+    #[storage_mapper("totalStaked")]
+    fn total_staked(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getStaked)]
+    #[storage_mapper("staked")]
+    fn staked(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getUnbonding)]
+    #[storage_mapper("unbonding")]
+    fn unbonding(
+        &self,
+        user: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedVec<UnbondingEntry<Self::Api>>>;
+
+    /// Topped-up rewards not yet allocated to stakers.
+    #[view(getRewardReserve)]
+    #[storage_mapper("rewardReserve")]
+    fn reward_reserve(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalRewardsDeposited)]
+    #[storage_mapper("totalRewardsDeposited")]
+    fn total_rewards_deposited(&self) -> SingleValueMapper<BigUint>;
+
+    /// Rewards claimed or compounded so far.
+    #[view(getTotalRewardsDistributed)]
+    #[storage_mapper("totalRewardsDistributed")]
+    fn total_rewards_distributed(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getRewardPerShare)]
+    #[storage_mapper("rewardPerShare")]
+    fn reward_per_share(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("lastUpdateBlock")]
+    fn last_update_block(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("userRewardPerShare")]
+    fn user_reward_per_share(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("pendingRewards")]
+    fn pending_rewards(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,12 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Unstaked amount that can be withdrawn from `unlock_block` on.
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug,
+)]
+pub struct UnbondingEntry<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub unlock_block: u64,
+}
@@ -0,0 +1,366 @@
+use multiversx_sc_scenario::imports::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use staking_rewards::staking_rewards_proxy;
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const STAKERS: [TestAddress; 4] = [
+    TestAddress::new("staker-1"),
+    TestAddress::new("staker-2"),
+    TestAddress::new("staker-3"),
+    TestAddress::new("staker-4"),
+];
+const STAKING_ADDRESS: TestSCAddress = TestSCAddress::new("staking-rewards");
+const REWARD_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("RWD-123456");
+const CODE_PATH: MxscPath = MxscPath::new("output/staking-rewards.mxsc.json");
+
+const INITIAL_BALANCE: u64 = 1_000_000_000;
+const INITIAL_REWARDS: u64 = 2_000;
+const UNBONDING_BLOCKS: u64 = 10;
+const STEPS: usize = 300;
+const SEEDS: [u64; 3] = [1, 42, 2024];
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/staking-rewards");
+    blockchain.register_contract(CODE_PATH, staking_rewards::ContractBuilder);
+    blockchain
+}
+
+/// Random walk over every endpoint. Only calls that should succeed are sent;
+/// after each one the reward accounting is checked against the deposits.
+struct StakingFuzzState {
+    world: ScenarioWorld,
+    rng: StdRng,
+    staking_token: EgldOrEsdtTokenIdentifier<StaticApi>,
+    block: u64,
+    /// Claimed and compounded rewards, as returned by the endpoints.
+    distributed: BigUint<StaticApi>,
+}
+
+impl StakingFuzzState {
+    /// Stakes EGLD, or the reward token itself when `stake_reward_token` is set,
+    /// which is what makes `compound` available.
+    fn new(seed: u64, stake_reward_token: bool) -> Self {
+        let mut world = world();
+        let staking_token = if stake_reward_token {
+            EgldOrEsdtTokenIdentifier::esdt(REWARD_TOKEN)
+        } else {
+            EgldOrEsdtTokenIdentifier::egld()
+        };
+
+        world
+            .account(OWNER_ADDRESS)
+            .nonce(1)
+            .esdt_balance(REWARD_TOKEN, INITIAL_BALANCE);
+        for staker in STAKERS {
+            world
+                .account(staker)
+                .balance(INITIAL_BALANCE)
+                .esdt_balance(REWARD_TOKEN, INITIAL_BALANCE);
+        }
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(staking_rewards_proxy::StakingRewardsProxy)
+            .init(&staking_token, REWARD_TOKEN, 100u64, UNBONDING_BLOCKS)
+            .code(CODE_PATH)
+            .new_address(STAKING_ADDRESS)
+            .run();
+
+        let mut state = Self {
+            world,
+            rng: StdRng::seed_from_u64(seed),
+            staking_token,
+            block: 0,
+            distributed: BigUint::zero(),
+        };
+        state.top_up(INITIAL_REWARDS);
+        state
+    }
+
+    fn random_staker(&mut self) -> TestAddress<'static> {
+        STAKERS[self.rng.gen_range(0..STAKERS.len())]
+    }
+
+    fn step(&mut self) {
+        let staker = self.random_staker();
+        match self.rng.gen_range(0..8) {
+            0 => {
+                let amount = self.rng.gen_range(1..=1_000u64);
+                self.stake(staker, amount);
+            }
+            1 => {
+                let staked = self.staked(staker).to_u64().unwrap();
+                if staked > 0 {
+                    let amount = self.rng.gen_range(1..=staked);
+                    self.unstake(staker, amount);
+                }
+            }
+            2 => self.withdraw(staker),
+            3 => self.claim(staker),
+            4 => self.compound(staker),
+            5 => {
+                let amount = self.rng.gen_range(1..=3_000u64);
+                self.top_up(amount);
+            }
+            6 => {
+                let rate = self.rng.gen_range(0..=300u64);
+                self.world
+                    .tx()
+                    .from(OWNER_ADDRESS)
+                    .to(STAKING_ADDRESS)
+                    .typed(staking_rewards_proxy::StakingRewardsProxy)
+                    .set_rewards_per_block(rate)
+                    .run();
+            }
+            _ => {
+                let blocks = self.rng.gen_range(1..=30u64);
+                self.advance_blocks(blocks);
+            }
+        }
+    }
+
+    fn advance_blocks(&mut self, blocks: u64) {
+        self.block += blocks;
+        self.world.current_block().block_nonce(self.block);
+    }
+
+    fn stake(&mut self, staker: TestAddress, amount: u64) {
+        self.world
+            .tx()
+            .from(staker)
+            .to(STAKING_ADDRESS)
+            .typed(staking_rewards_proxy::StakingRewardsProxy)
+            .stake()
+            .egld_or_single_esdt(&self.staking_token, 0u64, &BigUint::from(amount))
+            .run();
+    }
+
+    fn unstake(&mut self, staker: TestAddress, amount: u64) {
+        self.world
+            .tx()
+            .from(staker)
+            .to(STAKING_ADDRESS)
+            .typed(staking_rewards_proxy::StakingRewardsProxy)
+            .unstake(amount)
+            .run();
+    }
+
+    fn withdraw(&mut self, staker: TestAddress) {
+        let unlocked = self
+            .unbonding(staker)
+            .iter()
+            .any(|entry| entry.unlock_block <= self.block);
+        if !unlocked {
+            return;
+        }
+
+        self.world
+            .tx()
+            .from(staker)
+            .to(STAKING_ADDRESS)
+            .typed(staking_rewards_proxy::StakingRewardsProxy)
+            .withdraw()
+            .run();
+    }
+
+    fn claim(&mut self, staker: TestAddress) {
+        let pending = self.pending_rewards(staker);
+        if pending == 0 {
+            return;
+        }
+
+        let claimed = self
+            .world
+            .tx()
+            .from(staker)
+            .to(STAKING_ADDRESS)
+            .typed(staking_rewards_proxy::StakingRewardsProxy)
+            .claim()
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(claimed, pending);
+        self.distributed += claimed;
+    }
+
+    /// Falls back to `claim` when the staking token is not the reward token.
+    fn compound(&mut self, staker: TestAddress) {
+        if self.staking_token.is_egld() {
+            self.claim(staker);
+            return;
+        }
+        let pending = self.pending_rewards(staker);
+        if pending == 0 {
+            return;
+        }
+
+        let compounded = self
+            .world
+            .tx()
+            .from(staker)
+            .to(STAKING_ADDRESS)
+            .typed(staking_rewards_proxy::StakingRewardsProxy)
+            .compound()
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(compounded, pending);
+        self.distributed += compounded;
+    }
+
+    fn top_up(&mut self, amount: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(STAKING_ADDRESS)
+            .typed(staking_rewards_proxy::StakingRewardsProxy)
+            .top_up_rewards()
+            .single_esdt(
+                &REWARD_TOKEN.to_token_identifier(),
+                0u64,
+                &BigUint::from(amount),
+            )
+            .run();
+    }
+
+    fn staked(&mut self, staker: TestAddress) -> BigUint<StaticApi> {
+        self.world
+            .query()
+            .to(STAKING_ADDRESS)
+            .typed(staking_rewards_proxy::StakingRewardsProxy)
+            .staked(staker)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn unbonding(
+        &mut self,
+        staker: TestAddress,
+    ) -> ManagedVec<StaticApi, staking_rewards_proxy::UnbondingEntry<StaticApi>> {
+        self.world
+            .query()
+            .to(STAKING_ADDRESS)
+            .typed(staking_rewards_proxy::StakingRewardsProxy)
+            .unbonding(staker)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn pending_rewards(&mut self, staker: TestAddress) -> BigUint<StaticApi> {
+        self.world
+            .query()
+            .to(STAKING_ADDRESS)
+            .typed(staking_rewards_proxy::StakingRewardsProxy)
+            .get_pending_rewards(staker)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn total_staked(&mut self) -> BigUint<StaticApi> {
+        self.world
+            .query()
+            .to(STAKING_ADDRESS)
+            .typed(staking_rewards_proxy::StakingRewardsProxy)
+            .total_staked()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn total_rewards_deposited(&mut self) -> BigUint<StaticApi> {
+        self.world
+            .query()
+            .to(STAKING_ADDRESS)
+            .typed(staking_rewards_proxy::StakingRewardsProxy)
+            .total_rewards_deposited()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn total_rewards_distributed(&mut self) -> BigUint<StaticApi> {
+        self.world
+            .query()
+            .to(STAKING_ADDRESS)
+            .typed(staking_rewards_proxy::StakingRewardsProxy)
+            .total_rewards_distributed()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn check_invariants(&mut self) {
+        let deposited = self.total_rewards_deposited();
+        let distributed = self.total_rewards_distributed();
+        let total_staked = self.total_staked();
+        assert_eq!(distributed, self.distributed);
+
+        let mut total_pending = BigUint::zero();
+        let mut total_unbonding = BigUint::zero();
+        for staker in STAKERS {
+            total_pending += self.pending_rewards(staker);
+            for entry in self.unbonding(staker).iter() {
+                total_unbonding += &entry.amount;
+            }
+        }
+        assert!(
+            &distributed + &total_pending <= deposited,
+            "rewards owed exceed deposits: {distributed:?} distributed, {total_pending:?} pending, {deposited:?} deposited",
+        );
+
+        let rewards_left = &deposited - &distributed;
+        let stake_held = total_staked + total_unbonding;
+        if self.staking_token.is_egld() {
+            self.world
+                .check_account(STAKING_ADDRESS)
+                .balance(stake_held)
+                .esdt_balance(REWARD_TOKEN, rewards_left);
+        } else {
+            self.world
+                .check_account(STAKING_ADDRESS)
+                .esdt_balance(REWARD_TOKEN, stake_held + rewards_left);
+        }
+    }
+
+    /// Everyone leaves: after the unbonding period nothing is left but the reserve and rounding dust.
+    fn exit_all(&mut self) {
+        for staker in STAKERS {
+            let staked = self.staked(staker).to_u64().unwrap();
+            if staked > 0 {
+                self.unstake(staker, staked);
+            }
+            self.claim(staker);
+        }
+        self.advance_blocks(UNBONDING_BLOCKS);
+        for staker in STAKERS {
+            self.withdraw(staker);
+        }
+        self.check_invariants();
+
+        let total_staked = self.total_staked();
+        assert_eq!(total_staked, 0);
+        for staker in STAKERS {
+            assert_eq!(self.pending_rewards(staker), 0);
+            assert!(self.unbonding(staker).is_empty());
+        }
+    }
+}
+
+fn run_fuzz(stake_reward_token: bool) {
+    for seed in SEEDS {
+        let mut state = StakingFuzzState::new(seed, stake_reward_token);
+        for _ in 0..STEPS {
+            state.step();
+            state.check_invariants();
+        }
+        state.exit_all();
+    }
+}
+
+#[test]
+fn staking_rewards_fuzz_egld_stake() {
+    run_fuzz(false);
+}
+
+#[test]
+fn staking_rewards_fuzz_compound() {
+    run_fuzz(true);
+}
@@ -0,0 +1,36 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/staking-rewards");
+    blockchain.register_contract(
+        "mxsc:output/staking-rewards.mxsc.json",
+        staking_rewards::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn staking_rewards_compound_rs() {
+    world().run("scenarios/staking-rewards-compound.scen.json");
+}
+
+#[test]
+fn staking_rewards_long_unbonding_rs() {
+    world().run("scenarios/staking-rewards-long-unbonding.scen.json");
+}
+
+#[test]
+fn staking_rewards_reserve_rs() {
+    world().run("scenarios/staking-rewards-reserve.scen.json");
+}
+
+#[test]
+fn staking_rewards_stake_claim_rs() {
+    world().run("scenarios/staking-rewards-stake-claim.scen.json");
+}
+
+#[test]
+fn staking_rewards_unstake_rs() {
+    world().run("scenarios/staking-rewards-unstake.scen.json");
+}
@@ -0,0 +1,45 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           19
+// Async Callback (empty):               1
+// Total number of exported functions:  22
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    staking_rewards
+    (
+        init => init
+        upgrade => upgrade
+        stake => stake
+        unstake => unstake
+        withdraw => withdraw
+        claim => claim
+        compound => compound
+        topUpRewards => top_up_rewards
+        setRewardsPerBlock => set_rewards_per_block
+        getPendingRewards => get_pending_rewards
+        getStakingToken => staking_token
+        getRewardToken => reward_token
+        getRewardsPerBlock => rewards_per_block
+        getUnbondingBlocks => unbonding_blocks
+        getTotalStaked => total_staked
+        getStaked => staked
+        getUnbonding => unbonding
+        getRewardReserve => reward_reserve
+        getTotalRewardsDeposited => total_rewards_deposited
+        getTotalRewardsDistributed => total_rewards_distributed
+        getRewardPerShare => reward_per_share
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,200 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod operation;
+pub mod timelock_events;
+pub mod timelock_proxy;
+
+use operation::{Operation, OperationState, TimelockAction};
+
+pub const ERR_NO_ADMINS: &str = "no admins";
+pub const ERR_ZERO_DELAY: &str = "min delay cannot be zero";
+pub const ERR_DELAY_TOO_LONG: &str = "min delay cannot exceed one year";
+pub const ERR_ZERO_GRACE_PERIOD: &str = "grace period cannot be zero";
+pub const ERR_ONLY_ADMIN: &str = "only admins can do this";
+pub const ERR_ETA_TOO_SOON: &str = "eta must satisfy the minimum delay";
+pub const ERR_OPERATION_NOT_FOUND: &str = "operation does not exist";
+pub const ERR_NOT_READY: &str = "operation is not ready";
+pub const ERR_EXPIRED: &str = "operation has expired";
+pub const ERR_LAST_ADMIN: &str = "cannot remove the last admin";
+
+/// Upper bound for `minDelay`, so that `now + minDelay` stays far from overflowing.
+pub const MAX_MIN_DELAY: u64 = 365 * 24 * 60 * 60;
+
+/// Gas kept back for finishing `execute` after a call.
+const EXECUTE_FINISH_GAS: u64 = 300_000;
+
+/// Admins queue operations with an ETA at least `minDelay` seconds ahead, and execute them
+/// between the ETA and the end of the grace period. Contracts owned by the timelock can
+/// therefore only be called or upgraded after a public delay. Admin and delay changes
+/// go through the same queue.
+#[multiversx_sc::contract]
+pub trait Timelock: timelock_events::TimelockEventsModule {
+    #[init]
+    fn init(&self, min_delay: u64, grace_period: u64, admins: MultiValueEncoded<ManagedAddress>) {
+        self.require_valid_min_delay(min_delay);
+        require!(grace_period > 0, ERR_ZERO_GRACE_PERIOD);
+        self.min_delay().set(min_delay);
+        self.grace_period().set(grace_period);
+
+        self.admins().extend(admins);
+        require!(!self.admins().is_empty(), ERR_NO_ADMINS);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Queues `action` for execution from `eta` on. Returns the operation id.
+    #[endpoint]
+    fn queue(&self, action: TimelockAction<Self::Api>, eta: u64) -> u64 {
+        self.require_admin();
+        let earliest_eta = self
+            .blockchain()
+            .get_block_timestamp()
+            .checked_add(self.min_delay().get())
+            .unwrap_or_else(|| sc_panic!(ERR_ETA_TOO_SOON));
+        require!(eta >= earliest_eta, ERR_ETA_TOO_SOON);
+        if let TimelockAction::SetMinDelay(min_delay) = action {
+            self.require_valid_min_delay(min_delay);
+        }
+
+        let operation_id = self.last_operation_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        self.operation_queued_event(operation_id, eta, &action);
+        self.operations()
+            .insert(operation_id, Operation { action, eta });
+        operation_id
+    }
+
+    #[endpoint]
+    fn execute(&self, operation_id: u64) {
+        self.require_admin();
+        match self.get_operation_state(operation_id) {
+            OperationState::Pending => sc_panic!(ERR_NOT_READY),
+            OperationState::Ready => {}
+            OperationState::Expired => sc_panic!(ERR_EXPIRED),
+        }
+
+        // removed before any outgoing call, so the operation cannot be executed twice
+        let operation = self.get_operation(operation_id);
+        self.operations().remove(&operation_id);
+        self.operation_executed_event(operation_id);
+        self.perform_action(operation.action);
+    }
+
+    /// Drops a queued operation, whatever its state.
+    #[endpoint]
+    fn cancel(&self, operation_id: u64) {
+        self.require_admin();
+        require!(
+            self.operations().remove(&operation_id).is_some(),
+            ERR_OPERATION_NOT_FOUND
+        );
+        self.operation_cancelled_event(operation_id);
+    }
+
+    fn perform_action(&self, action: TimelockAction<Self::Api>) {
+        match action {
+            TimelockAction::Call {
+                to,
+                endpoint_name,
+                arguments,
+            } => {
+                let gas = self.blockchain().get_gas_left();
+                require!(gas > EXECUTE_FINISH_GAS, "insufficient gas for call");
+                self.tx()
+                    .to(to)
+                    .gas(gas - EXECUTE_FINISH_GAS)
+                    .raw_call(endpoint_name)
+                    .arguments_raw(arguments.into())
+                    .sync_call();
+            }
+            TimelockAction::UpgradeFromSource {
+                sc_address,
+                source,
+                code_metadata,
+                arguments,
+            } => {
+                let gas_left = self.blockchain().get_gas_left();
+                self.tx()
+                    .to(sc_address)
+                    .gas(gas_left)
+                    .raw_upgrade()
+                    .from_source(source)
+                    .code_metadata(code_metadata)
+                    .arguments_raw(arguments.into())
+                    .upgrade_async_call_and_exit();
+            }
+            TimelockAction::AddAdmin(admin) => {
+                self.admins().insert(admin);
+            }
+            TimelockAction::RemoveAdmin(admin) => {
+                self.admins().swap_remove(&admin);
+                require!(!self.admins().is_empty(), ERR_LAST_ADMIN);
+            }
+            TimelockAction::SetMinDelay(min_delay) => {
+                self.min_delay().set(min_delay);
+            }
+        }
+    }
+
+    fn require_valid_min_delay(&self, min_delay: u64) {
+        require!(min_delay > 0, ERR_ZERO_DELAY);
+        require!(min_delay <= MAX_MIN_DELAY, ERR_DELAY_TOO_LONG);
+    }
+
+    fn require_admin(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(self.admins().contains(&caller), ERR_ONLY_ADMIN);
+    }
+
+    #[view(getOperationState)]
+    fn get_operation_state(&self, operation_id: u64) -> OperationState {
+        let operation = self.get_operation(operation_id);
+        let now = self.blockchain().get_block_timestamp();
+        if now < operation.eta {
+            OperationState::Pending
+        } else if now <= operation.eta.saturating_add(self.grace_period().get()) {
+            OperationState::Ready
+        } else {
+            OperationState::Expired
+        }
+    }
+
+    #[view(getOperation)]
+    fn get_operation(&self, operation_id: u64) -> Operation<Self::Api> {
+        self.operations()
+            .get(&operation_id)
+            .unwrap_or_else(|| sc_panic!(ERR_OPERATION_NOT_FOUND))
+    }
+
+    /// Ids of the operations queued and neither executed nor cancelled.
+    #[view(getQueuedOperationIds)]
+    fn get_queued_operation_ids(&self) -> MultiValueEncoded<u64> {
+        self.operations().keys().collect()
+    }
+
+    #[view(getAdmins)]
+    #[storage_mapper("admins")]
+    fn admins(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Minimum seconds between queueing an operation and its ETA.
+    #[view(getMinDelay)]
+    #[storage_mapper("minDelay")]
+    fn min_delay(&self) -> SingleValueMapper<u64>;
+
+    /// Seconds after the ETA during which an operation can still be executed.
+    #[view(getGracePeriod)]
+    #[storage_mapper("gracePeriod")]
+    fn grace_period(&self) -> SingleValueMapper<u64>;
+
+    #[view(getLastOperationId)]
+    #[storage_mapper("lastOperationId")]
+    fn last_operation_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("operations")]
+    fn operations(&self) -> MapMapper<u64, Operation<Self::Api>>;
+}
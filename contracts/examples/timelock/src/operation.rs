@@ -0,0 +1,43 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// What a queued operation does once it is executed.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, PartialEq, Debug)]
+pub enum TimelockAction<M: ManagedTypeApi> {
+    /// Synchronous call to `endpoint_name` on `to`.
+    Call {
+        to: ManagedAddress<M>,
+        endpoint_name: ManagedBuffer<M>,
+        arguments: ManagedVec<M, ManagedBuffer<M>>,
+    },
+    /// Upgrades a contract owned by the timelock with the code of `source`.
+    UpgradeFromSource {
+        sc_address: ManagedAddress<M>,
+        source: ManagedAddress<M>,
+        code_metadata: CodeMetadata,
+        arguments: ManagedVec<M, ManagedBuffer<M>>,
+    },
+    AddAdmin(ManagedAddress<M>),
+    RemoveAdmin(ManagedAddress<M>),
+    SetMinDelay(u64),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum OperationState {
+    /// Queued, before its ETA.
+    Pending,
+    /// Between its ETA and the end of the grace period.
+    Ready,
+    /// Past the grace period; it can only be cancelled.
+    Expired,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Operation<M: ManagedTypeApi> {
+    pub action: TimelockAction<M>,
+    /// Block timestamp from which the operation can be executed.
+    pub eta: u64,
+}
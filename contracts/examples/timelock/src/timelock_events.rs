@@ -0,0 +1,21 @@
+multiversx_sc::imports!();
+
+use crate::operation::TimelockAction;
+
+/// Operation lifecycle events, with the operation id as the first topic.
+#[multiversx_sc::module]
+pub trait TimelockEventsModule {
+    #[event("operationQueued")]
+    fn operation_queued_event(
+        &self,
+        #[indexed] operation_id: u64,
+        #[indexed] eta: u64,
+        action: &TimelockAction<Self::Api>,
+    );
+
+    #[event("operationExecuted")]
+    fn operation_executed_event(&self, #[indexed] operation_id: u64);
+
+    #[event("operationCancelled")]
+    fn operation_cancelled_event(&self, #[indexed] operation_id: u64);
+}
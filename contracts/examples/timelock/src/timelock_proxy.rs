@@ -0,0 +1,252 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct TimelockProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for TimelockProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = TimelockProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        TimelockProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct TimelockProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> TimelockProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        min_delay: Arg0,
+        grace_period: Arg1,
+        admins: Arg2,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&min_delay)
+            .argument(&grace_period)
+            .argument(&admins)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> TimelockProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> TimelockProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Queues `action` for execution from `eta` on. Returns the operation id. 
+    pub fn queue<
+        Arg0: ProxyArg<TimelockAction<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        action: Arg0,
+        eta: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("queue")
+            .argument(&action)
+            .argument(&eta)
+            .original_result()
+    }
+
+    pub fn execute<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        operation_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("execute")
+            .argument(&operation_id)
+            .original_result()
+    }
+
+    /// Drops a queued operation, whatever its state. 
+    pub fn cancel<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        operation_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancel")
+            .argument(&operation_id)
+            .original_result()
+    }
+
+    pub fn get_operation_state<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        operation_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OperationState> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOperationState")
+            .argument(&operation_id)
+            .original_result()
+    }
+
+    pub fn get_operation<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        operation_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Operation<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOperation")
+            .argument(&operation_id)
+            .original_result()
+    }
+
+    /// Ids of the operations queued and neither executed nor cancelled. 
+    pub fn get_queued_operation_ids(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQueuedOperationIds")
+            .original_result()
+    }
+
+    pub fn admins(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAdmins")
+            .original_result()
+    }
+
+    /// Minimum seconds between queueing an operation and its ETA. 
+    pub fn min_delay(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinDelay")
+            .original_result()
+    }
+
+    /// Seconds after the ETA during which an operation can still be executed. 
+    pub fn grace_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGracePeriod")
+            .original_result()
+    }
+
+    pub fn last_operation_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastOperationId")
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, PartialEq, Debug)]
+pub enum TimelockAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Call {
+        to: ManagedAddress<Api>,
+        endpoint_name: ManagedBuffer<Api>,
+        arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+    },
+    UpgradeFromSource {
+        sc_address: ManagedAddress<Api>,
+        source: ManagedAddress<Api>,
+        code_metadata: CodeMetadata,
+        arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+    },
+    AddAdmin(ManagedAddress<Api>),
+    RemoveAdmin(ManagedAddress<Api>),
+    SetMinDelay(u64),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum OperationState {
+    Pending,
+    Ready,
+    Expired,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Operation<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action: TimelockAction<Api>,
+    pub eta: u64,
+}
@@ -0,0 +1,41 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/timelock");
+    blockchain.register_contract("mxsc:output/timelock.mxsc.json", timelock::ContractBuilder);
+    blockchain.register_contract(
+        "mxsc:../adder/output/adder.mxsc.json",
+        adder::ContractBuilder,
+    );
+    blockchain.register_contract(
+        "mxsc:../counter/output/counter.mxsc.json",
+        counter::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn timelock_admin_rs() {
+    world().run("scenarios/timelock-admin.scen.json");
+}
+
+#[test]
+fn timelock_expiry_cancel_rs() {
+    world().run("scenarios/timelock-expiry-cancel.scen.json");
+}
+
+#[test]
+fn timelock_queue_execute_rs() {
+    world().run("scenarios/timelock-queue-execute.scen.json");
+}
+
+#[test]
+fn timelock_setup_rs() {
+    world().run("scenarios/timelock-setup.scen.json");
+}
+
+#[test]
+fn timelock_upgrade_rs() {
+    world().run("scenarios/timelock-upgrade.scen.json");
+}
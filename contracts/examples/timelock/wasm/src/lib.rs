@@ -0,0 +1,36 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           10
+// Async Callback (empty):               1
+// Total number of exported functions:  13
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    timelock
+    (
+        init => init
+        upgrade => upgrade
+        queue => queue
+        execute => execute
+        cancel => cancel
+        getOperationState => get_operation_state
+        getOperation => get_operation
+        getQueuedOperationIds => get_queued_operation_ids
+        getAdmins => admins
+        getMinDelay => min_delay
+        getGracePeriod => grace_period
+        getLastOperationId => last_operation_id
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
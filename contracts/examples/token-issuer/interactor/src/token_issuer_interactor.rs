@@ -0,0 +1,309 @@
+mod token_issuer_interactor_cli;
+mod token_issuer_interactor_config;
+
+use clap::Parser;
+use deployment_registry::{DeploymentRecord, DeploymentRegistry, RegistryError};
+use token_issuer::token_issuer_proxy;
+pub use token_issuer_interactor_config::Config;
+
+use multiversx_sc_snippets::{imports::*, multiversx_sc::contract_base::ContractAbiProvider};
+
+const CONTRACT_NAME: &str = "token-issuer";
+const TOKEN_ISSUER_CODE_PATH: MxscPath = MxscPath::new("../output/token-issuer.mxsc.json");
+
+/// Fungible token issue cost on every network: 0.05 EGLD.
+const ISSUE_COST: u64 = 50_000_000_000_000_000;
+
+pub async fn token_issuer_cli() {
+    env_logger::init();
+
+    let cli = token_issuer_interactor_cli::InteractCli::parse();
+    let mut config = Config::load_config();
+    if let Some(gateway) = cli.gateway {
+        config.gateway_uri = gateway;
+    }
+    if let Some(pem) = cli.pem {
+        config.owner_pem = Some(pem);
+    }
+
+    let mut interact = TokenIssuerInteract::new(config).await;
+    if let Err(err) = run_command(&mut interact, &cli.command).await {
+        println!("{err}");
+    }
+}
+
+async fn run_command(
+    interact: &mut TokenIssuerInteract,
+    command: &Option<token_issuer_interactor_cli::InteractCliCommand>,
+) -> Result<(), RegistryError> {
+    match command {
+        Some(token_issuer_interactor_cli::InteractCliCommand::Deploy) => {
+            interact.deploy().await;
+        }
+        Some(token_issuer_interactor_cli::InteractCliCommand::Upgrade) => {
+            interact.upgrade().await?;
+        }
+        Some(token_issuer_interactor_cli::InteractCliCommand::Issue(args)) => {
+            interact
+                .issue_token(&args.name, &args.ticker, args.num_decimals)
+                .await?;
+        }
+        Some(token_issuer_interactor_cli::InteractCliCommand::Mint(args)) => {
+            interact.mint(big_uint(args.amount)).await?;
+        }
+        Some(token_issuer_interactor_cli::InteractCliCommand::Burn(args)) => {
+            interact.burn(big_uint(args.amount)).await?;
+        }
+        Some(token_issuer_interactor_cli::InteractCliCommand::Distribute(args)) => {
+            let transfers = args.transfers.iter().map(|t| parse_transfer(t)).collect();
+            interact.distribute(transfers).await?;
+        }
+        Some(token_issuer_interactor_cli::InteractCliCommand::Status) => {
+            println!("token id: {}", interact.token_id().await?);
+            println!("reserve: {}", interact.reserve().await?);
+        }
+        None => {}
+    }
+
+    Ok(())
+}
+
+fn big_uint(value: u128) -> BigUint<StaticApi> {
+    BigUint::from_bytes_be(&value.to_be_bytes())
+}
+
+fn parse_transfer(transfer: &str) -> (Bech32Address, BigUint<StaticApi>) {
+    let (address, amount) = transfer
+        .split_once('=')
+        .unwrap_or_else(|| panic!("expected <address>=<amount>, got {transfer}"));
+    let amount = amount
+        .parse::<u128>()
+        .unwrap_or_else(|err| panic!("invalid amount in {transfer}: {err}"));
+    (
+        Bech32Address::from_bech32_string(address.to_owned()),
+        big_uint(amount),
+    )
+}
+
+pub struct TokenIssuerInteract {
+    pub interactor: Interactor,
+    pub owner_address: Bech32Address,
+    pub network: String,
+    pub registry: DeploymentRegistry,
+}
+
+impl TokenIssuerInteract {
+    pub async fn new(config: Config) -> Self {
+        let mut interactor = Interactor::new(&config.gateway_uri)
+            .await
+            .use_chain_simulator(config.use_chain_simulator());
+        interactor.set_current_dir_from_workspace("examples/token-issuer/interactor");
+
+        let owner_address = interactor.register_wallet(config.owner_wallet()).await;
+
+        // generate blocks until ESDTSystemSCActivationEpoch in order to be able to use the chain simulator
+        interactor.generate_blocks_until_epoch(1).await.unwrap();
+
+        let registry = DeploymentRegistry::load_default().unwrap_or_else(|err| {
+            println!("deployment registry not loaded: {err}");
+            DeploymentRegistry::default()
+        });
+
+        TokenIssuerInteract {
+            interactor,
+            owner_address: owner_address.into(),
+            network: config.network,
+            registry,
+        }
+    }
+
+    pub async fn deploy(&mut self) {
+        let (new_address, tx_hash) = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .gas(20_000_000)
+            .typed(token_issuer_proxy::TokenIssuerProxy)
+            .init()
+            .code(TOKEN_ISSUER_CODE_PATH)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .returns(ReturnsNewBech32Address)
+            .returns(ReturnsTxHash)
+            .run()
+            .await;
+
+        println!("new address: {new_address}");
+        if let Err(err) = self
+            .record_deployment(new_address, tx_hash.as_bytes())
+            .await
+        {
+            println!("deployment not recorded in the registry: {err}");
+        }
+    }
+
+    pub async fn upgrade(&mut self) -> Result<(), RegistryError> {
+        let token_issuer_address = self.token_issuer_address()?;
+        let tx_hash = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&token_issuer_address)
+            .gas(20_000_000)
+            .typed(token_issuer_proxy::TokenIssuerProxy)
+            .upgrade()
+            .code(TOKEN_ISSUER_CODE_PATH)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .returns(ReturnsTxHash)
+            .run()
+            .await;
+
+        println!("upgraded {token_issuer_address}");
+        if let Err(err) = self
+            .record_deployment(token_issuer_address, tx_hash.as_bytes())
+            .await
+        {
+            println!("deployment not recorded in the registry: {err}");
+        }
+
+        Ok(())
+    }
+
+    /// Issues through the system SC; the token identifier is only known once the callback has run.
+    pub async fn issue_token(
+        &mut self,
+        name: &str,
+        ticker: &str,
+        num_decimals: usize,
+    ) -> Result<(), RegistryError> {
+        let token_issuer_address = self.token_issuer_address()?;
+        self.interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&token_issuer_address)
+            .gas(100_000_000)
+            .typed(token_issuer_proxy::TokenIssuerProxy)
+            .issue_token(name, ticker, num_decimals)
+            .egld(ISSUE_COST)
+            .run()
+            .await;
+
+        println!("issued {}", self.token_id().await?);
+
+        Ok(())
+    }
+
+    pub async fn mint(&mut self, amount: BigUint<StaticApi>) -> Result<(), RegistryError> {
+        let token_issuer_address = self.token_issuer_address()?;
+        self.interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&token_issuer_address)
+            .gas(10_000_000)
+            .typed(token_issuer_proxy::TokenIssuerProxy)
+            .mint(amount)
+            .run()
+            .await;
+
+        println!("reserve: {}", self.reserve().await?);
+
+        Ok(())
+    }
+
+    pub async fn burn(&mut self, amount: BigUint<StaticApi>) -> Result<(), RegistryError> {
+        let token_issuer_address = self.token_issuer_address()?;
+        self.interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&token_issuer_address)
+            .gas(10_000_000)
+            .typed(token_issuer_proxy::TokenIssuerProxy)
+            .burn(amount)
+            .run()
+            .await;
+
+        println!("reserve: {}", self.reserve().await?);
+
+        Ok(())
+    }
+
+    pub async fn distribute(
+        &mut self,
+        transfers: Vec<(Bech32Address, BigUint<StaticApi>)>,
+    ) -> Result<(), RegistryError> {
+        let token_issuer_address = self.token_issuer_address()?;
+        let mut args = MultiValueEncoded::new();
+        for (receiver, amount) in transfers {
+            args.push(MultiValue2::from((receiver.to_address().into(), amount)));
+        }
+
+        self.interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(&token_issuer_address)
+            .gas(10_000_000 + 1_000_000 * args.len() as u64)
+            .typed(token_issuer_proxy::TokenIssuerProxy)
+            .distribute(args)
+            .run()
+            .await;
+
+        println!("reserve: {}", self.reserve().await?);
+
+        Ok(())
+    }
+
+    pub async fn token_id(&mut self) -> Result<String, RegistryError> {
+        let token_issuer_address = self.token_issuer_address()?;
+        let token_id = self
+            .interactor
+            .query()
+            .to(&token_issuer_address)
+            .typed(token_issuer_proxy::TokenIssuerProxy)
+            .token()
+            .returns(ReturnsResult)
+            .run()
+            .await
+            .to_string();
+        Ok(token_id)
+    }
+
+    pub async fn reserve(&mut self) -> Result<RustBigUint, RegistryError> {
+        let token_issuer_address = self.token_issuer_address()?;
+        let reserve = self
+            .interactor
+            .query()
+            .to(&token_issuer_address)
+            .typed(token_issuer_proxy::TokenIssuerProxy)
+            .reserve()
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+        Ok(reserve)
+    }
+
+    /// Address of the token-issuer contract recorded for the configured network.
+    pub fn token_issuer_address(&self) -> Result<Bech32Address, RegistryError> {
+        self.registry.address(&self.network, CONTRACT_NAME).cloned()
+    }
+
+    async fn record_deployment(
+        &mut self,
+        address: Bech32Address,
+        tx_hash: &[u8],
+    ) -> Result<(), RegistryError> {
+        let abi_version = token_issuer::AbiProvider::abi()
+            .build_info
+            .contract_crate
+            .version;
+        let record = DeploymentRecord::fetch(
+            &self.interactor,
+            address,
+            self.owner_address.clone(),
+            tx_hash,
+            abi_version,
+        )
+        .await?;
+
+        self.registry
+            .record_and_save(&self.network, CONTRACT_NAME, record)
+    }
+}
@@ -0,0 +1,62 @@
+use clap::{Args, Parser, Subcommand};
+
+/// Token Issuer Interact CLI
+#[derive(Default, PartialEq, Eq, Debug, Parser)]
+#[command(version, about)]
+#[command(propagate_version = true)]
+pub struct InteractCli {
+    /// Overrides `gateway_uri` from config.toml
+    #[arg(long, global = true)]
+    pub gateway: Option<String>,
+
+    /// Overrides `owner_pem` from config.toml
+    #[arg(long, global = true)]
+    pub pem: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<InteractCliCommand>,
+}
+
+/// Token Issuer Interact CLI Commands
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum InteractCliCommand {
+    #[command(name = "deploy", about = "Deploy contract")]
+    Deploy,
+    #[command(name = "upgrade", about = "Upgrade contract")]
+    Upgrade,
+    #[command(name = "issue", about = "Issue the token and set the local roles")]
+    Issue(IssueArgs),
+    #[command(name = "mint", about = "Mint into the contract reserve")]
+    Mint(AmountArgs),
+    #[command(name = "burn", about = "Burn from the contract reserve")]
+    Burn(AmountArgs),
+    #[command(name = "distribute", about = "Send tokens from the reserve")]
+    Distribute(DistributeArgs),
+    #[command(name = "status", about = "Print the token identifier and reserve")]
+    Status,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct IssueArgs {
+    /// Token display name
+    pub name: String,
+
+    /// Token ticker, 3 to 10 uppercase alphanumeric characters
+    pub ticker: String,
+
+    #[arg(long, default_value_t = 18)]
+    pub num_decimals: usize,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AmountArgs {
+    /// Amount, in the smallest token unit
+    pub amount: u128,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct DistributeArgs {
+    /// Transfers as `<bech32 address>=<amount>`
+    #[arg(required = true)]
+    pub transfers: Vec<String>,
+}
@@ -0,0 +1,6 @@
+use multiversx_sc_snippets::imports::*;
+
+#[tokio::main]
+async fn main() {
+    token_issuer_interactor::token_issuer_cli().await;
+}
@@ -0,0 +1,24 @@
+use multiversx_sc_snippets::imports::*;
+use token_issuer_interactor::{Config, TokenIssuerInteract};
+
+#[tokio::test]
+#[cfg_attr(not(feature = "chain-simulator-tests"), ignore)]
+async fn token_issuer_simulator_issue_mint_distribute() {
+    let mut interact = TokenIssuerInteract::new(Config::chain_simulator_config()).await;
+    let owner_address = interact.owner_address.clone();
+
+    interact.deploy().await;
+    interact
+        .issue_token("IssuerTest", "ISSUER", 18)
+        .await
+        .unwrap();
+    assert!(interact.token_id().await.unwrap().starts_with("ISSUER-"));
+
+    interact.mint(BigUint::from(1_000u64)).await.unwrap();
+    interact.burn(BigUint::from(100u64)).await.unwrap();
+    interact
+        .distribute(vec![(owner_address, BigUint::from(400u64))])
+        .await
+        .unwrap();
+    assert_eq!(interact.reserve().await.unwrap(), RustBigUint::from(500u64));
+}
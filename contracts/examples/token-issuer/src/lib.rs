@@ -0,0 +1,121 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod token_issuer_proxy;
+
+pub const ERR_INSUFFICIENT_RESERVE: &str = "insufficient token reserve";
+pub const ERR_NO_RECEIVERS: &str = "no receivers";
+pub const ERR_ZERO_AMOUNT: &str = "amount cannot be zero";
+
+/// Issues a fungible ESDT through the system SC and holds the minted supply as its own
+/// balance (the reserve), from which the owner burns or distributes tokens.
+#[multiversx_sc::contract]
+pub trait TokenIssuer {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Issues the token and grants this contract the local mint/burn roles in one system SC call.
+    /// The EGLD payment is the issue cost; it is returned to the caller if the issue fails.
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueToken)]
+    fn issue_token(
+        &self,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+        num_decimals: usize,
+    ) {
+        let issue_cost = self.call_value().egld().clone_value();
+        let caller = self.blockchain().get_caller();
+        self.token().issue_and_set_all_roles(
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            num_decimals,
+            Some(self.callbacks().issue_callback(&caller)),
+        );
+    }
+
+    #[callback]
+    fn issue_callback(
+        &self,
+        caller: &ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.token().set_token_id(token_id);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.token().clear();
+                let returned = self.call_value().egld().clone_value();
+                self.tx().to(caller).egld(returned).transfer_if_not_empty();
+            }
+        }
+    }
+
+    /// Mints `amount` into the reserve.
+    #[only_owner]
+    #[endpoint]
+    fn mint(&self, amount: BigUint) {
+        require!(amount > 0, ERR_ZERO_AMOUNT);
+        self.token().mint(amount);
+    }
+
+    /// Burns `amount` from the reserve.
+    #[only_owner]
+    #[endpoint]
+    fn burn(&self, amount: BigUint) {
+        require!(amount > 0, ERR_ZERO_AMOUNT);
+        require!(
+            amount <= self.token().get_balance(),
+            ERR_INSUFFICIENT_RESERVE
+        );
+        self.token().burn(&amount);
+    }
+
+    /// Sends each `(receiver, amount)` pair out of the reserve.
+    /// Fails as a whole if the reserve cannot cover the sum.
+    #[only_owner]
+    #[endpoint]
+    fn distribute(&self, transfers: MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>>) {
+        require!(!transfers.is_empty(), ERR_NO_RECEIVERS);
+
+        let mut total = BigUint::zero();
+        for transfer in transfers.clone() {
+            let (_, amount) = transfer.into_tuple();
+            require!(amount > 0, ERR_ZERO_AMOUNT);
+            total += amount;
+        }
+        require!(
+            total <= self.token().get_balance(),
+            ERR_INSUFFICIENT_RESERVE
+        );
+
+        let token_id = self.token().get_token_id();
+        for transfer in transfers {
+            let (receiver, amount) = transfer.into_tuple();
+            self.tx()
+                .to(&receiver)
+                .single_esdt(&token_id, 0, &amount)
+                .transfer();
+        }
+    }
+
+    /// Amount of the issued token currently held by the contract.
+    #[view(getReserve)]
+    fn reserve(&self) -> BigUint {
+        if !self.token().get_token_state().is_set() {
+            return BigUint::zero();
+        }
+        self.token().get_balance()
+    }
+
+    #[view(getTokenId)]
+    #[storage_mapper("token")]
+    fn token(&self) -> FungibleTokenMapper;
+}
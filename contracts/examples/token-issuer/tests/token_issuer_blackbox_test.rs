@@ -0,0 +1,102 @@
+use multiversx_sc::storage::{mappers::TokenMapperState, StorageKey};
+use multiversx_sc_scenario::imports::*;
+
+use token_issuer::{token_issuer_proxy, TokenIssuer};
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const TOKEN_ISSUER_ADDRESS: TestSCAddress = TestSCAddress::new("token-issuer");
+const CODE_PATH: MxscPath = MxscPath::new("output/token-issuer.mxsc.json");
+const ISSUE_COST: u64 = 50_000_000_000_000_000;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/token-issuer");
+    blockchain.register_contract(CODE_PATH, token_issuer::ContractBuilder);
+    blockchain
+}
+
+/// Deploys, then leaves the contract as `issueToken` does while the system SC call is
+/// in flight: the token mapper pending and the issue cost already paid away by the owner.
+fn setup_pending_issue() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER_ADDRESS).nonce(1).balance(0);
+    world.set_state_step(SetStateStep::new().put_account(
+        "0x000000000000000000010000000000000000000000000000000000000002ffff",
+        Account::new().code("str:esdt-system-sc-mock"),
+    ));
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(token_issuer_proxy::TokenIssuerProxy)
+        .init()
+        .code(CODE_PATH)
+        .new_address(TOKEN_ISSUER_ADDRESS)
+        .run();
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(TOKEN_ISSUER_ADDRESS)
+        .whitebox(token_issuer::contract_obj, |_| {
+            SingleValueMapper::<DebugApi, TokenMapperState<DebugApi>>::new(StorageKey::new(
+                b"token",
+            ))
+            .set(TokenMapperState::Pending);
+        });
+
+    world
+}
+
+/// The system SC mock never fails an issue, so the callback is driven directly,
+/// the way the protocol calls it back with the issue cost after a failure.
+#[test]
+fn token_issuer_blackbox_failed_issue_refunds_cost() {
+    let mut world = setup_pending_issue();
+
+    world
+        .tx()
+        .from(ESDTSystemSCAddress.to_address())
+        .to(TOKEN_ISSUER_ADDRESS)
+        .egld(ISSUE_COST)
+        .whitebox(token_issuer::contract_obj, |sc| {
+            sc.issue_callback(
+                &OWNER_ADDRESS.to_managed_address(),
+                ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                    err_code: 10,
+                    err_msg: ManagedBuffer::from("ticker name is not valid"),
+                }),
+            );
+        });
+
+    world.check_account(OWNER_ADDRESS).balance(ISSUE_COST);
+    world.check_account(TOKEN_ISSUER_ADDRESS).balance(0);
+    world
+        .query()
+        .to(TOKEN_ISSUER_ADDRESS)
+        .whitebox(token_issuer::contract_obj, |sc| {
+            assert!(sc.token().is_empty());
+        });
+    world
+        .query()
+        .to(TOKEN_ISSUER_ADDRESS)
+        .typed(token_issuer_proxy::TokenIssuerProxy)
+        .reserve()
+        .returns(ExpectValue(0u32))
+        .run();
+
+    // the cleared mapper lets the owner try again
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(TOKEN_ISSUER_ADDRESS)
+        .typed(token_issuer_proxy::TokenIssuerProxy)
+        .issue_token("Token", "TKN", 18u32)
+        .egld(ISSUE_COST)
+        .run();
+    world
+        .query()
+        .to(TOKEN_ISSUER_ADDRESS)
+        .whitebox(token_issuer::contract_obj, |sc| {
+            assert!(sc.token().get_token_state().is_set());
+        });
+}
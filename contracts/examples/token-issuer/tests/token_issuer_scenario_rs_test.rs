@@ -0,0 +1,26 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/token-issuer");
+    blockchain.register_contract(
+        "mxsc:output/token-issuer.mxsc.json",
+        token_issuer::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn token_issuer_distribute_rs() {
+    world().run("scenarios/token-issuer-distribute.scen.json");
+}
+
+#[test]
+fn token_issuer_issue_rs() {
+    world().run("scenarios/token-issuer-issue.scen.json");
+}
+
+#[test]
+fn token_issuer_mint_burn_rs() {
+    world().run("scenarios/token-issuer-mint-burn.scen.json");
+}
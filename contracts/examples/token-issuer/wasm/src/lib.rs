@@ -0,0 +1,32 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            6
+// Async Callback:                       1
+// Total number of exported functions:   9
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    token_issuer
+    (
+        init => init
+        upgrade => upgrade
+        issueToken => issue_token
+        mint => mint
+        burn => burn
+        distribute => distribute
+        getReserve => reserve
+        getTokenId => token
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback! { token_issuer }
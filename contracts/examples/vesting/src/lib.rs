@@ -0,0 +1,199 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod schedule;
+pub mod vesting_proxy;
+
+use schedule::VestingSchedule;
+
+pub const ERR_WRONG_TOKEN: &str = "wrong token";
+pub const ERR_ZERO_AMOUNT: &str = "amount cannot be zero";
+pub const ERR_ZERO_DURATION: &str = "duration cannot be zero";
+pub const ERR_CLIFF_TOO_LONG: &str = "cliff cannot exceed the duration";
+pub const ERR_END_OVERFLOW: &str = "schedule end overflows";
+pub const ERR_INSUFFICIENT_UNALLOCATED: &str = "not enough unallocated tokens";
+pub const ERR_SCHEDULE_NOT_FOUND: &str = "schedule does not exist";
+pub const ERR_NOT_REVOCABLE: &str = "schedule is not revocable";
+pub const ERR_ALREADY_REVOKED: &str = "schedule already revoked";
+pub const ERR_NOTHING_TO_CLAIM: &str = "nothing to claim";
+
+/// Locks an ESDT deposited by the owner into per-beneficiary vesting schedules.
+/// Beneficiaries claim whatever has vested so far at any time.
+#[multiversx_sc::contract]
+pub trait Vesting {
+    #[init]
+    fn init(&self, token_id: TokenIdentifier) {
+        require!(token_id.is_valid_esdt_identifier(), ERR_WRONG_TOKEN);
+        self.token_id().set(token_id);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Adds tokens to the unallocated pool that schedules are created from.
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint]
+    fn deposit(&self) {
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.token_id().get(),
+            ERR_WRONG_TOKEN
+        );
+        require!(payment.amount > 0, ERR_ZERO_AMOUNT);
+
+        self.unallocated()
+            .update(|unallocated| *unallocated += &payment.amount);
+    }
+
+    /// Reserves `total_amount` of the unallocated pool for `beneficiary`. Returns the schedule id.
+    #[only_owner]
+    #[endpoint(createSchedule)]
+    fn create_schedule(
+        &self,
+        beneficiary: ManagedAddress,
+        total_amount: BigUint,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+        revocable: bool,
+    ) -> usize {
+        require!(total_amount > 0, ERR_ZERO_AMOUNT);
+        require!(duration > 0, ERR_ZERO_DURATION);
+        require!(cliff <= duration, ERR_CLIFF_TOO_LONG);
+        require!(start.checked_add(duration).is_some(), ERR_END_OVERFLOW);
+        self.take_unallocated(&total_amount);
+
+        let schedule_id = self.schedules().push(&VestingSchedule {
+            beneficiary: beneficiary.clone(),
+            total_amount,
+            claimed: BigUint::zero(),
+            start,
+            cliff,
+            duration,
+            revocable,
+            revoked_at: None,
+        });
+        self.beneficiary_schedules(&beneficiary).insert(schedule_id);
+
+        schedule_id
+    }
+
+    /// Stops a revocable schedule now. What has vested so far stays claimable;
+    /// the rest goes back to the unallocated pool.
+    #[only_owner]
+    #[endpoint]
+    fn revoke(&self, schedule_id: usize) {
+        let mut schedule = self.require_schedule(schedule_id);
+        require!(schedule.revocable, ERR_NOT_REVOCABLE);
+        require!(schedule.revoked_at.is_none(), ERR_ALREADY_REVOKED);
+
+        let now = self.blockchain().get_block_timestamp();
+        let unvested = &schedule.total_amount - &schedule.vested_amount(now);
+        schedule.revoked_at = Some(now);
+        self.schedules().set(schedule_id, &schedule);
+
+        self.unallocated()
+            .update(|unallocated| *unallocated += unvested);
+    }
+
+    /// Sends `amount` of the unallocated pool back to the owner.
+    #[only_owner]
+    #[endpoint(withdrawUnallocated)]
+    fn withdraw_unallocated(&self, amount: BigUint) {
+        require!(amount > 0, ERR_ZERO_AMOUNT);
+        self.take_unallocated(&amount);
+
+        let owner = self.blockchain().get_owner_address();
+        self.tx()
+            .to(&owner)
+            .single_esdt(&self.token_id().get(), 0, &amount)
+            .transfer();
+    }
+
+    /// Sends the caller everything vested and not yet claimed across their schedules.
+    /// Returns the amount sent.
+    #[endpoint]
+    fn claim(&self) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        let now = self.blockchain().get_block_timestamp();
+
+        let mut total_claimed = BigUint::zero();
+        for schedule_id in self.beneficiary_schedules(&caller).iter() {
+            let mut schedule = self.schedules().get(schedule_id);
+            let claimable = schedule.claimable_amount(now);
+            if claimable == 0 {
+                continue;
+            }
+            schedule.claimed += &claimable;
+            self.schedules().set(schedule_id, &schedule);
+            total_claimed += claimable;
+        }
+        require!(total_claimed > 0, ERR_NOTHING_TO_CLAIM);
+
+        self.tx()
+            .to(&caller)
+            .single_esdt(&self.token_id().get(), 0, &total_claimed)
+            .transfer();
+
+        total_claimed
+    }
+
+    fn take_unallocated(&self, amount: &BigUint) {
+        let unallocated = self.unallocated().get();
+        require!(*amount <= unallocated, ERR_INSUFFICIENT_UNALLOCATED);
+        self.unallocated().set(unallocated - amount);
+    }
+
+    fn require_schedule(&self, schedule_id: usize) -> VestingSchedule<Self::Api> {
+        require!(
+            schedule_id > 0 && schedule_id <= self.schedules().len(),
+            ERR_SCHEDULE_NOT_FOUND
+        );
+        self.schedules().get(schedule_id)
+    }
+
+    /// What `beneficiary` could claim at `timestamp`, across all their schedules.
+    #[view(getClaimableAmount)]
+    fn get_claimable_amount(&self, beneficiary: ManagedAddress, timestamp: u64) -> BigUint {
+        let mut claimable = BigUint::zero();
+        for schedule_id in self.beneficiary_schedules(&beneficiary).iter() {
+            claimable += self
+                .schedules()
+                .get(schedule_id)
+                .claimable_amount(timestamp);
+        }
+        claimable
+    }
+
+    #[view(getVestedAmount)]
+    fn get_vested_amount(&self, schedule_id: usize, timestamp: u64) -> BigUint {
+        self.require_schedule(schedule_id).vested_amount(timestamp)
+    }
+
+    #[view(getSchedule)]
+    fn get_schedule(&self, schedule_id: usize) -> VestingSchedule<Self::Api> {
+        self.require_schedule(schedule_id)
+    }
+
+    #[view(getBeneficiarySchedules)]
+    fn get_beneficiary_schedules(&self, beneficiary: ManagedAddress) -> MultiValueEncoded<usize> {
+        self.beneficiary_schedules(&beneficiary).iter().collect()
+    }
+
+    #[view(getTokenId)]
+    #[storage_mapper("tokenId")]
+    fn token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    /// Deposited tokens not reserved by any schedule.
+    #[view(getUnallocated)]
+    #[storage_mapper("unallocated")]
+    fn unallocated(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("schedules")]
+    fn schedules(&self) -> VecMapper<VestingSchedule<Self::Api>>;
+
+    #[storage_mapper("beneficiarySchedules")]
+    fn beneficiary_schedules(&self, beneficiary: &ManagedAddress) -> UnorderedSetMapper<usize>;
+}
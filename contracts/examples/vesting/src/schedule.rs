@@ -0,0 +1,47 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Linear release of `total_amount` over `duration` seconds from `start`,
+/// with nothing released before `start + cliff`. A cliff equal to the duration
+/// releases everything at once.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct VestingSchedule<M: ManagedTypeApi> {
+    pub beneficiary: ManagedAddress<M>,
+    pub total_amount: BigUint<M>,
+    pub claimed: BigUint<M>,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+    pub revocable: bool,
+    /// Vesting stops at this timestamp once the owner revokes the schedule.
+    pub revoked_at: Option<u64>,
+}
+
+impl<M: ManagedTypeApi> VestingSchedule<M> {
+    /// Amount vested at `timestamp`, rounded down.
+    pub fn vested_amount(&self, timestamp: u64) -> BigUint<M> {
+        let timestamp = match self.revoked_at {
+            Some(revoked_at) => timestamp.min(revoked_at),
+            None => timestamp,
+        };
+        if timestamp < self.start.saturating_add(self.cliff) {
+            return BigUint::zero();
+        }
+        let elapsed = timestamp - self.start;
+        if elapsed >= self.duration {
+            return self.total_amount.clone();
+        }
+        &self.total_amount * &BigUint::from(elapsed) / BigUint::from(self.duration)
+    }
+
+    /// Vested but not yet claimed at `timestamp`; 0 for timestamps before the last claim.
+    pub fn claimable_amount(&self, timestamp: u64) -> BigUint<M> {
+        let vested = self.vested_amount(timestamp);
+        if vested > self.claimed {
+            vested - &self.claimed
+        } else {
+            BigUint::zero()
+        }
+    }
+}
@@ -0,0 +1,259 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct VestingProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for VestingProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = VestingProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        VestingProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct VestingProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> VestingProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&token_id)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> VestingProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> VestingProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Adds tokens to the unallocated pool that schedules are created from. 
+    pub fn deposit(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit")
+            .original_result()
+    }
+
+    /// Reserves `total_amount` of the unallocated pool for `beneficiary`. Returns the schedule id. 
+    pub fn create_schedule<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<bool>,
+    >(
+        self,
+        beneficiary: Arg0,
+        total_amount: Arg1,
+        start: Arg2,
+        cliff: Arg3,
+        duration: Arg4,
+        revocable: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createSchedule")
+            .argument(&beneficiary)
+            .argument(&total_amount)
+            .argument(&start)
+            .argument(&cliff)
+            .argument(&duration)
+            .argument(&revocable)
+            .original_result()
+    }
+
+    /// Stops a revocable schedule now. What has vested so far stays claimable; 
+    /// the rest goes back to the unallocated pool. 
+    pub fn revoke<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        schedule_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revoke")
+            .argument(&schedule_id)
+            .original_result()
+    }
+
+    /// Sends `amount` of the unallocated pool back to the owner. 
+    pub fn withdraw_unallocated<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawUnallocated")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Sends the caller everything vested and not yet claimed across their schedules. 
+    /// Returns the amount sent. 
+    pub fn claim(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claim")
+            .original_result()
+    }
+
+    /// What `beneficiary` could claim at `timestamp`, across all their schedules. 
+    pub fn get_claimable_amount<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        beneficiary: Arg0,
+        timestamp: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimableAmount")
+            .argument(&beneficiary)
+            .argument(&timestamp)
+            .original_result()
+    }
+
+    pub fn get_vested_amount<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        schedule_id: Arg0,
+        timestamp: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVestedAmount")
+            .argument(&schedule_id)
+            .argument(&timestamp)
+            .original_result()
+    }
+
+    pub fn get_schedule<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        schedule_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, VestingSchedule<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSchedule")
+            .argument(&schedule_id)
+            .original_result()
+    }
+
+    pub fn get_beneficiary_schedules<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        beneficiary: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBeneficiarySchedules")
+            .argument(&beneficiary)
+            .original_result()
+    }
+
+    pub fn token_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenId")
+            .original_result()
+    }
+
+    /// Deposited tokens not reserved by any schedule. 
+    pub fn unallocated(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnallocated")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct VestingSchedule<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub beneficiary: ManagedAddress<Api>,
+    pub total_amount: BigUint<Api>,
+    pub claimed: BigUint<Api>,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+    pub revocable: bool,
+    pub revoked_at: Option<u64>,
+}
@@ -0,0 +1,28 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("examples/vesting");
+    blockchain.register_contract("mxsc:output/vesting.mxsc.json", vesting::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn vesting_cliff_rs() {
+    world().run("scenarios/vesting-cliff.scen.json");
+}
+
+#[test]
+fn vesting_linear_rs() {
+    world().run("scenarios/vesting-linear.scen.json");
+}
+
+#[test]
+fn vesting_revoke_rs() {
+    world().run("scenarios/vesting-revoke.scen.json");
+}
+
+#[test]
+fn vesting_setup_rs() {
+    world().run("scenarios/vesting-setup.scen.json");
+}
@@ -0,0 +1,37 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           11
+// Async Callback (empty):               1
+// Total number of exported functions:  14
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    vesting
+    (
+        init => init
+        upgrade => upgrade
+        deposit => deposit
+        createSchedule => create_schedule
+        revoke => revoke
+        withdrawUnallocated => withdraw_unallocated
+        claim => claim
+        getClaimableAmount => get_claimable_amount
+        getVestedAmount => get_vested_amount
+        getSchedule => get_schedule
+        getBeneficiarySchedules => get_beneficiary_schedules
+        getTokenId => token_id
+        getUnallocated => unallocated
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
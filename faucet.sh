@@ -4,6 +4,7 @@
 # MultiversX Testnet Faucet Script
 # Description: Requests test tokens from MultiversX Testnet faucet
 # Usage: ./faucet.sh <your_address>
+#        FAUCET_CONTRACT=<erd1...> ./faucet.sh <your_address>   (localnet faucet contract)
 ################################################################################
 
 set -e
@@ -19,6 +20,12 @@ NC='\033[0m' # No Color
 # Testnet faucet URL
 FAUCET_URL="https://r3d4.fr/faucet"
 
+# Localnet faucet contract (contracts/examples/faucet), used instead of the URL when set
+FAUCET_CONTRACT="${FAUCET_CONTRACT:-}"
+LOCALNET_PROXY="${LOCALNET_PROXY:-http://localhost:7950}"
+LOCALNET_CHAIN="${LOCALNET_CHAIN:-localnet}"
+WALLET_PEM="${WALLET_PEM:-./wallets/wallet-owner.pem}"
+
 echo -e "${CYAN}========================================${NC}"
 echo -e "${CYAN}  💧 MultiversX Testnet Faucet${NC}"
 echo -e "${CYAN}========================================${NC}"
@@ -48,6 +55,31 @@ if [[ ! "$ADDRESS" =~ ^erd1 ]]; then
 fi
 
 echo -e "${BLUE}Address:${NC} ${ADDRESS}"
+
+if [ -n "$FAUCET_CONTRACT" ]; then
+    echo -e "${BLUE}Faucet contract:${NC} ${FAUCET_CONTRACT}"
+    echo ""
+    echo -e "${YELLOW}Claiming from the localnet faucet contract...${NC}"
+    echo ""
+
+    if mxpy contract call "$FAUCET_CONTRACT" \
+        --function=claim \
+        --arguments "$ADDRESS" \
+        --pem="$WALLET_PEM" \
+        --proxy="$LOCALNET_PROXY" \
+        --chain="$LOCALNET_CHAIN" \
+        --recall-nonce \
+        --gas-limit=20000000 \
+        --send; then
+        echo -e "${GREEN}✓ Claim sent for ${ADDRESS}${NC}"
+    else
+        echo -e "${RED}Claim failed!${NC}"
+        echo -e "${YELLOW}The address may still be in its cooldown, or the faucet may need a refill.${NC}"
+        exit 1
+    fi
+    exit 0
+fi
+
 echo -e "${BLUE}Faucet:${NC} ${FAUCET_URL}"
 echo ""
 echo -e "${YELLOW}Requesting test EGLD from faucet...${NC}"
@@ -13,7 +13,7 @@ TEST_RESULTS_DIR="./test-results"
 WALLET_PEM="./testnet/wallets/users/alice.pem"
 TEST_DURATION=60
 MAX_TPS_TARGET=50
-TEST_CONTRACT_DIR="./contracts/test"
+TEST_CONTRACT_DIR="./contracts/examples/ping-pong"
 
 # Colors
 RED='\033[0;31m'
@@ -33,7 +33,6 @@ success() { echo -e "${GREEN}[$(date +'%H:%M:%S')] SUCCESS:${NC} $1"; }
 # Create test directories
 setup_test_environment() {
     mkdir -p "$TEST_RESULTS_DIR"
-    mkdir -p "$TEST_CONTRACT_DIR"
     mkdir -p "./test-data/wallets"
     mkdir -p "./test-data/transactions"
 }
@@ -178,17 +177,18 @@ test_smart_contract_deployment() {
     local test_file="$TEST_RESULTS_DIR/contract_test.json"
     local start_time=$(date +%s.%N)
     
-    # Create a simple test contract if it doesn't exist
-    if [ ! -f "$TEST_CONTRACT_DIR/test.wasm" ]; then
-        warn "No test contract found, creating simple ping-pong contract..."
-        create_test_contract
+    # Build the ping-pong example if it hasn't been built yet
+    if [ ! -f "$TEST_CONTRACT_DIR/output/ping-pong.wasm" ]; then
+        warn "No ping-pong build found, building contracts/examples/ping-pong..."
+        build_test_contract || true
     fi
     
-    local contract_file="$TEST_CONTRACT_DIR/test.wasm"
+    local contract_file="$TEST_CONTRACT_DIR/output/ping-pong.wasm"
     local wallet_file="./test-data/wallets/test_wallet_1.pem"
     
     if [ -f "$contract_file" ] && [ -f "$wallet_file" ]; then
-        if mxpy contract deploy --bytecode="$contract_file" --pem="$wallet_file" --gas-limit=5000000 --send --outfile="$TEST_RESULTS_DIR/deploy_result.json" > /dev/null 2>&1; then
+        # ping amount 0.001 EGLD, 60 second ping window
+        if mxpy contract deploy --bytecode="$contract_file" --arguments 1000000000000000 60 --pem="$wallet_file" --gas-limit=10000000 --send --outfile="$TEST_RESULTS_DIR/deploy_result.json" > /dev/null 2>&1; then
             local end_time=$(date +%s.%N)
             local duration=$(echo "$end_time - $start_time" | bc -l)
             
@@ -203,51 +203,14 @@ test_smart_contract_deployment() {
     fi
 }
 
-# Create a simple test contract
-create_test_contract() {
-    mkdir -p "$TEST_CONTRACT_DIR"
-    
-    # Create a simple Rust smart contract
-    cat > "$TEST_CONTRACT_DIR/lib.rs" << 'EOF'
-#![no_std]
-
-use multiversx_sc::derive::{ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi};
-use multiversx_sc::imports::*;
-
-#[multiversx_sc::contract]
-pub trait TestContract {
-    #[init]
-    fn init(&self) {}
-    
-    #[endpoint]
-    fn ping(&self) -> ManagedBuffer {
-        ManagedBuffer::from(b"pong")
-    }
-    
-    #[endpoint]
-    fn sum(&self, a: BigUint, b: BigUint) -> BigUint {
-        a + b
-    }
-}
-EOF
-
-    # Create Cargo.toml
-    cat > "$TEST_CONTRACT_DIR/Cargo.toml" << 'EOF'
-[package]
-name = "test-contract"
-version = "0.1.0"
-edition = "2021"
-
-[dependencies]
-multiversx-sc = "0.50.0"
-
-[lib]
-name = "test_contract"
-path = "lib.rs"
-crate-type = ["cdylib"]
-EOF
+# Build the ping-pong example contract
+build_test_contract() {
+    if ! sc-meta all build --path "$TEST_CONTRACT_DIR" > "$TEST_RESULTS_DIR/contract_build.log" 2>&1; then
+        error "Ping-pong build failed, see $TEST_RESULTS_DIR/contract_build.log"
+        return 1
+    fi
 
-    log "Test contract template created"
+    log "Ping-pong contract built"
 }
 
 # Network stress test